@@ -0,0 +1,243 @@
+/*
+Benchmark of the solvers over their stored inputs: each input is
+solved several times, timing the parsing and the solving phases
+separately. The report can be output as a table, JSON or CSV to
+compare the performances between commits. Any other input can be
+benchmarked too, like the actual puzzle inputs which are not stored.
+ */
+
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::golden;
+use crate::registry::{self, Part};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format '{s}', expected table, json or csv")),
+        }
+    }
+}
+
+// Summary of the durations of several runs
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            max: sorted[n - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub day: u32,
+    pub part: Part,
+    pub input: PathBuf,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+// The (day, part, input, params file) of the stored case files, an input
+// used for several expected outputs is only benchmarked once.
+type StoredInput = (u32, Part, PathBuf, Option<PathBuf>);
+
+fn stored_inputs(root: &Path, day: Option<u32>, part: Option<Part>) -> io::Result<Vec<StoredInput>> {
+    let mut inputs: Vec<StoredInput> = golden::discover(root)?
+        .into_iter()
+        .filter(|c| day.is_none_or(|d| d == c.day) && part.is_none_or(|p| p == c.part))
+        .map(|c| (c.day, c.part, c.input, c.params))
+        .collect();
+    inputs.sort();
+    inputs.dedup();
+    Ok(inputs)
+}
+
+// Solves the input `runs` times, after a first warm-up run. The runtime
+// parameters are given as on the command line ("--param steps=6").
+pub fn bench_input(day: u32, part: Part, input: &Path, args: &[String], runs: usize) -> Result<BenchResult, String> {
+    let puzzle = registry::find(day, part).ok_or(format!("no solver for day {day} part {part}"))?;
+    let data = fs::read(input).map_err(|e| format!("can not read {}: {e}", input.display()))?;
+
+    let mut params = puzzle.default_params();
+    params.set_args(args)?;
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    let mut total = Vec::with_capacity(runs);
+    for k in 0..=runs {
+        let start = Instant::now();
+        // a panicking solver only fails its own input
+        match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.run)(&mut data.as_slice(), &params))) {
+            Err(_) => return Err("solver panicked".to_string()),
+            Ok(Err(e)) => return Err(format!("input error: {e}")),
+            Ok(Ok(_)) => {}
+        }
+        let elapsed = start.elapsed();
+        if k == 0 {
+            continue; // warm-up
+        }
+        let t = aoc_common::last_timings();
+        parse.push(t.parse);
+        solve.push(t.solve);
+        total.push(elapsed);
+    }
+
+    Ok(BenchResult {
+        day,
+        part,
+        input: input.to_path_buf(),
+        runs,
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve),
+        total: Stats::from_samples(&total),
+    })
+}
+
+// Benchmarks all the stored inputs, or only the ones of a day/part.
+// The inputs which can not be solved are reported on stderr and skipped.
+pub fn bench_all(root: &Path, day: Option<u32>, part: Option<Part>, runs: usize) -> io::Result<Vec<BenchResult>> {
+    let mut results = Vec::new();
+    for (d, p, input, params) in stored_inputs(root, day, part)? {
+        let args = match params.map(fs::read_to_string).transpose() {
+            Ok(s) => s.unwrap_or_default().split_whitespace().map(String::from).collect::<Vec<_>>(),
+            Err(e) => {
+                eprintln!("day {d} part {p} {}: can not read its params: {e}", input.display());
+                continue;
+            }
+        };
+        match bench_input(d, p, &input, &args, runs) {
+            Ok(mut r) => {
+                r.input = input.strip_prefix(root).unwrap_or(&input).to_path_buf();
+                results.push(r);
+            }
+            Err(e) => eprintln!("day {d} part {p} {}: {e}", input.display()),
+        }
+    }
+    Ok(results)
+}
+
+fn json_stats(s: &Stats) -> String {
+    format!(
+        "{{\"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"max_ns\": {}, \"stddev_ns\": {}}}",
+        s.min.as_nanos(),
+        s.median.as_nanos(),
+        s.mean.as_nanos(),
+        s.max.as_nanos(),
+        s.stddev.as_nanos()
+    )
+}
+
+// Minimal escaping, enough for the file names
+fn json_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub fn report(results: &[BenchResult], format: Format) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Table => {
+            out.push_str(&format!(
+                "day part  {:<40} {:>5} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+                "input", "runs", "parse", "solve", "total", "min", "stddev"
+            ));
+            for r in results {
+                out.push_str(&format!(
+                    "{:>3} {:>4}  {:<40} {:>5} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+                    r.day,
+                    r.part,
+                    r.input.display(),
+                    r.runs,
+                    format!("{:.2?}", r.parse.median),
+                    format!("{:.2?}", r.solve.median),
+                    format!("{:.2?}", r.total.median),
+                    format!("{:.2?}", r.total.min),
+                    format!("{:.2?}", r.total.stddev),
+                ));
+            }
+        }
+        Format::Json => {
+            out.push_str("[\n");
+            for (k, r) in results.iter().enumerate() {
+                out.push_str(&format!(
+                    "  {{\"day\": {}, \"part\": \"{}\", \"input\": {}, \"runs\": {}, \"parse\": {}, \"solve\": {}, \"total\": {}}}{}\n",
+                    r.day,
+                    r.part,
+                    json_string(&r.input.display().to_string()),
+                    r.runs,
+                    json_stats(&r.parse),
+                    json_stats(&r.solve),
+                    json_stats(&r.total),
+                    if k + 1 < results.len() { "," } else { "" }
+                ));
+            }
+            out.push_str("]\n");
+        }
+        Format::Csv => {
+            out.push_str("day,part,input,runs,phase,min_ns,median_ns,mean_ns,max_ns,stddev_ns\n");
+            for r in results {
+                for (phase, s) in [("parse", &r.parse), ("solve", &r.solve), ("total", &r.total)] {
+                    out.push_str(&format!(
+                        "{},{},{},{},{},{},{},{},{},{}\n",
+                        r.day,
+                        r.part,
+                        r.input.display(),
+                        r.runs,
+                        phase,
+                        s.min.as_nanos(),
+                        s.median.as_nanos(),
+                        s.mean.as_nanos(),
+                        s.max.as_nanos(),
+                        s.stddev.as_nanos()
+                    ));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,264 @@
+/*
+Golden-file regression tests: run the solvers on the inputs stored next
+to their expected output. Both naming schemes of the repository are found:
+  day_XX/day_XX-P_case_N_input.txt   and  day_XX/day_XX-P_case_N_output.txt
+  day_XX-P/input_N.txt               and  day_XX-P/output_N.txt
+A case may also have runtime parameters, for the samples needing other
+values than the actual input, in the same arguments as on the command
+line ("--param steps=6"):
+  day_XX/day_XX-P_case_N_params.txt  or   day_XX-P/params_N.txt
+ */
+
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use aoc_common::Answer;
+
+use crate::registry::{self, Part};
+
+// Expected outputs not matched yet by the solver, identified by the
+// output file path in the workspace. They are reported but don't fail
+// the whole check, until they are fixed and must be removed from here.
+const KNOWN_FAILURES: &[&str] = &[
+    // the edges to cut are hardcoded for the actual puzzle input
+    "day_25-A/output_1.txt",
+];
+
+pub struct Case {
+    pub day: u32,
+    pub part: Part,
+    pub input: PathBuf,
+    pub output: PathBuf,
+    // the arguments of the params file, if any
+    pub params: Option<PathBuf>,
+}
+
+pub enum Status {
+    Pass,
+    Fail { expected: String, got: String },
+    // missing solver, unreadable file, panic...
+    Error(String),
+}
+
+pub struct Outcome {
+    pub case: Case,
+    pub status: Status,
+    pub known_failure: bool,
+}
+
+impl Outcome {
+    // A known failure suddenly passing is also reported, so that
+    // it gets removed from the list.
+    pub fn is_regression(&self) -> bool {
+        matches!(self.status, Status::Pass) == self.known_failure
+    }
+}
+
+// The case files are stored in the day directories of the workspace
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate is inside the workspace")
+        .to_path_buf()
+}
+
+// "day_03-A" => (3, Some(A)), "day_09-AB" or "day_08" => (9, None)
+fn parse_day_dir(name: &str) -> Option<(u32, Option<Part>)> {
+    let rest = name.strip_prefix("day_")?;
+    let (day, part) = match rest.split_once('-') {
+        Some((day, part)) => (day, Part::from_str(part).ok()),
+        None => (rest, None),
+    };
+    Some((u32::from_str(day).ok()?, part))
+}
+
+// "day_08-B_case_1_output.txt" => (8, "B", "1")
+fn parse_case_output(name: &str) -> Option<(u32, &str, &str)> {
+    let rest = name.strip_prefix("day_")?.strip_suffix("_output.txt")?;
+    let (day, rest) = rest.split_once('-')?;
+    let (part, case) = rest.split_once("_case_")?;
+    Some((u32::from_str(day).ok()?, part, case))
+}
+
+// The puzzle input is the same for both parts, so an expected output
+// may have been stored only along the other part, or the "AB" one.
+// For the actual puzzle input, the case is also named after the part.
+fn find_case_input(dir: &Path, day: u32, part: &str, case: &str) -> Option<PathBuf> {
+    let other = if part == "A" { "B" } else { "A" };
+    let cases: &[&str] = if ["A", "B", "AB"].contains(&case) {
+        &[case, "A", "B", "AB"]
+    } else {
+        &[case]
+    };
+    for p in [part, "AB", other] {
+        for c in cases {
+            let input = dir.join(format!("day_{day:02}-{p}_case_{c}_input.txt"));
+            if input.is_file() {
+                return Some(input);
+            }
+        }
+    }
+    None
+}
+
+// The actual puzzle input stored along the samples, its case being named
+// after the part: "input_A.txt", "day_08-B_case_B_input.txt"...
+pub fn is_puzzle_input(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    let case = name
+        .strip_prefix("input_")
+        .and_then(|n| n.strip_suffix(".txt"))
+        .or_else(|| Some(name.strip_suffix("_input.txt")?.split_once("_case_")?.1));
+    case.is_some_and(|c| ["A", "B", "AB"].contains(&c))
+}
+
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+// Finds all the input/output pairs of the workspace
+pub fn discover(root: &Path) -> io::Result<Vec<Case>> {
+    let mut cases = Vec::new();
+
+    for dir in sorted_entries(root)? {
+        if !dir.is_dir() {
+            continue;
+        }
+        let Some((dir_day, dir_part)) = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(parse_day_dir)
+        else {
+            continue;
+        };
+
+        for output in sorted_entries(&dir)? {
+            let Some(name) = output.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+
+            // the empty test cases created by "aoc new" are not filled yet
+            if fs::metadata(&output).is_ok_and(|m| m.len() == 0) {
+                continue;
+            }
+
+            if let Some((day, part, case)) = parse_case_output(name) {
+                let (Ok(p), Some(input)) = (Part::from_str(part), find_case_input(&dir, day, part, case))
+                else {
+                    continue;
+                };
+                let params = Some(dir.join(name.replace("_output.txt", "_params.txt"))).filter(|p| p.is_file());
+                cases.push(Case { day, part: p, input, output, params });
+            } else if let Some(n) = name.strip_prefix("output_").and_then(|n| n.strip_suffix(".txt")) {
+                // only the directories of a single part use this naming
+                let input = dir.join(format!("input_{n}.txt"));
+                let params = Some(dir.join(format!("params_{n}.txt"))).filter(|p| p.is_file());
+                if let (Some(part), true) = (dir_part, input.is_file()) {
+                    cases.push(Case { day: dir_day, part, input, output, params });
+                }
+            }
+        }
+    }
+
+    Ok(cases)
+}
+
+pub fn run_case(case: &Case) -> Status {
+    let Some(puzzle) = registry::find(case.day, case.part) else {
+        return Status::Error(format!("no solver for day {} part {}", case.day, case.part));
+    };
+    let expected = match fs::read_to_string(&case.output) {
+        Ok(s) => s,
+        Err(e) => return Status::Error(format!("can not read {}: {e}", case.output.display())),
+    };
+    let mut reader = match File::open(&case.input) {
+        Ok(f) => BufReader::new(f),
+        Err(e) => return Status::Error(format!("can not open {}: {e}", case.input.display())),
+    };
+    let mut params = puzzle.default_params();
+    if let Some(file) = &case.params {
+        let args = match fs::read_to_string(file) {
+            Ok(s) => s.split_whitespace().map(String::from).collect::<Vec<_>>(),
+            Err(e) => return Status::Error(format!("can not read {}: {e}", file.display())),
+        };
+        if let Err(e) = params.set_args(&args) {
+            return Status::Error(format!("{}: {e}", file.display()));
+        }
+    }
+
+    match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.run)(&mut reader, &params))) {
+        Err(_) => Status::Error("solver panicked".to_string()),
+        Ok(Err(e)) => Status::Error(format!("input error: {e}")),
+        Ok(Ok(got)) if Ok(&got) == Answer::from_str(&expected).as_ref() => Status::Pass,
+        Ok(Ok(got)) => Status::Fail { expected: expected.trim().to_string(), got: got.to_string() },
+    }
+}
+
+// Runs all the cases found, or only the ones of a single day
+pub fn run_all(root: &Path, day: Option<u32>) -> io::Result<Vec<Outcome>> {
+    Ok(discover(root)?
+        .into_iter()
+        .filter(|c| day.is_none_or(|d| d == c.day))
+        .map(|case| {
+            let status = run_case(&case);
+            let known_failure = case
+                .output
+                .strip_prefix(root)
+                .is_ok_and(|p| KNOWN_FAILURES.iter().any(|k| p == Path::new(k)));
+            Outcome { case, status, known_failure }
+        })
+        .collect())
+}
+
+pub fn print_table(root: &Path, outcomes: &[Outcome]) {
+    println!("day part  {:<44} result", "expected output");
+    for o in outcomes {
+        let output = o.case.output.strip_prefix(root).unwrap_or(&o.case.output);
+        let result = match (&o.status, o.known_failure) {
+            (Status::Pass, false) => "ok".to_string(),
+            (Status::Pass, true) => "FIXED: remove it from the known failures".to_string(),
+            (Status::Fail { expected, got }, known) => format!(
+                "{}: expected {expected}, got {got}",
+                if known { "known failure" } else { "FAIL" }
+            ),
+            (Status::Error(e), known) => format!(
+                "{}: {e}",
+                if known { "known failure" } else { "ERROR" }
+            ),
+        };
+        println!("{:>3} {:>4}  {:<44} {}", o.case.day, o.case.part, output.display(), result);
+    }
+
+    let regressions = outcomes.iter().filter(|o| o.is_regression()).count();
+    let known = outcomes.iter().filter(|o| o.known_failure && !o.is_regression()).count();
+    println!(
+        "{} cases: {} passed, {} failed, {} known failures",
+        outcomes.len(),
+        outcomes.len() - regressions - known,
+        regressions,
+        known
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn golden_files() {
+        let root = workspace_root();
+        let outcomes = run_all(&root, None).expect("case files are readable");
+        print_table(&root, &outcomes);
+        assert!(!outcomes.is_empty());
+        assert!(outcomes.iter().all(|o| !o.is_regression()));
+    }
+}
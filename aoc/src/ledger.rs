@@ -0,0 +1,203 @@
+/*
+Ledger of the answers submitted on the Advent of Code site for the
+actual puzzle input: the accepted one, and the rejected guesses with
+their too high / too low hint. Each new result of a solver can then be
+checked against what is already known.
+
+The ledger is a text file, one answer per line:
+  <day> <part> <verdict> <answer>
+with the verdicts ok, high, low or wrong (rejected without hint).
+ */
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::registry::Part;
+
+pub const LEDGER_FILE: &str = "answers.txt";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ok" => Ok(Verdict::Accepted),
+            "high" => Ok(Verdict::TooHigh),
+            "low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!("invalid verdict '{s}', expected ok, high, low or wrong")),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Accepted => f.pad("ok"),
+            Verdict::TooHigh => f.pad("high"),
+            Verdict::TooLow => f.pad("low"),
+            Verdict::Wrong => f.pad("wrong"),
+        }
+    }
+}
+
+pub struct Entry {
+    pub day: u32,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+// What the ledger knows about a new answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    // nothing recorded for this puzzle
+    Unknown,
+    // same as the accepted answer
+    Accepted,
+    // an answer was already accepted, and it is not this one
+    Differs(String),
+    // this answer was already submitted and rejected
+    Rejected(Verdict),
+    // not submitted yet, but too high or too low like a previous guess
+    TooHigh(String),
+    TooLow(String),
+    // not submitted yet, and within the known bounds
+    Plausible,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Unknown => write!(f, "no answer recorded yet"),
+            Check::Accepted => write!(f, "accepted answer"),
+            Check::Differs(a) => write!(f, "WRONG, the accepted answer is {a}"),
+            Check::Rejected(Verdict::TooHigh) => write!(f, "WRONG, already rejected as too high"),
+            Check::Rejected(Verdict::TooLow) => write!(f, "WRONG, already rejected as too low"),
+            Check::Rejected(_) => write!(f, "WRONG, already rejected"),
+            Check::TooHigh(a) => write!(f, "WRONG, {a} was already too high"),
+            Check::TooLow(a) => write!(f, "WRONG, {a} was already too low"),
+            Check::Plausible => write!(f, "not submitted yet, within the known bounds"),
+        }
+    }
+}
+
+impl Check {
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Check::Differs(_) | Check::Rejected(_) | Check::TooHigh(_) | Check::TooLow(_))
+    }
+}
+
+pub struct Ledger {
+    path: PathBuf,
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    // A missing file is an empty ledger
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut ledger = Ledger { path: path.to_path_buf(), entries: Vec::new() };
+        let text = match fs::read_to_string(path) {
+            Ok(t) => t,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(ledger),
+            Err(e) => return Err(format!("can not read {}: {e}", path.display())),
+        };
+
+        for (n, l) in text.lines().enumerate() {
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+            let invalid = |e: String| format!("{}:{}: {e}", path.display(), n + 1);
+            let fields: Vec<&str> = l.split_whitespace().collect();
+            let [day, part, verdict, answer] = fields[..] else {
+                return Err(invalid(format!("expected <day> <part> <verdict> <answer>, got '{l}'")));
+            };
+            ledger.entries.push(Entry {
+                day: u32::from_str(day).map_err(|_| invalid(format!("invalid day '{day}'")))?,
+                part: Part::from_str(part).map_err(invalid)?,
+                verdict: Verdict::from_str(verdict).map_err(invalid)?,
+                answer: answer.to_string(),
+            });
+        }
+        Ok(ledger)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut text = String::from(
+            "# Answers submitted for the actual puzzle inputs, see aoc/src/ledger.rs\n\
+             # <day> <part> <verdict: ok, high, low or wrong> <answer>\n",
+        );
+        for e in &self.entries {
+            text.push_str(&format!("{} {} {} {}\n", e.day, e.part, e.verdict, e.answer));
+        }
+        fs::write(&self.path, text)
+    }
+
+    pub fn entries_of(&self, day: u32, part: Part) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |e| e.day == day && e.part == part)
+    }
+
+    // Adds a submitted answer, refusing the ones contradicting the ledger
+    pub fn record(&mut self, day: u32, part: Part, verdict: Verdict, answer: &str) -> Result<(), String> {
+        if let Some(e) = self.entries_of(day, part).find(|e| e.answer == answer) {
+            if e.verdict == verdict {
+                return Ok(());
+            }
+            return Err(format!("{answer} is already recorded as {}", e.verdict));
+        }
+        if verdict == Verdict::Accepted {
+            if let Some(e) = self.entries_of(day, part).find(|e| e.verdict == Verdict::Accepted) {
+                return Err(format!("{} is already the accepted answer", e.answer));
+            }
+        }
+
+        self.entries.push(Entry { day, part, verdict, answer: answer.to_string() });
+        self.entries.sort_by_key(|e| (e.day, e.part));
+        Ok(())
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Check {
+        let mut entries = self.entries_of(day, part).peekable();
+        if entries.peek().is_none() {
+            return Check::Unknown;
+        }
+
+        let value = i128::from_str(answer).ok();
+        let mut lowest_high: Option<(i128, &str)> = None;
+        let mut highest_low: Option<(i128, &str)> = None;
+        for e in entries {
+            if e.verdict == Verdict::Accepted {
+                return if e.answer == answer { Check::Accepted } else { Check::Differs(e.answer.clone()) };
+            }
+            if e.answer == answer {
+                return Check::Rejected(e.verdict);
+            }
+            let Ok(v) = i128::from_str(&e.answer) else {
+                continue;
+            };
+            match e.verdict {
+                Verdict::TooHigh if lowest_high.is_none_or(|(h, _)| v < h) => lowest_high = Some((v, &e.answer)),
+                Verdict::TooLow if highest_low.is_none_or(|(l, _)| v > l) => highest_low = Some((v, &e.answer)),
+                _ => {}
+            }
+        }
+
+        match (value, lowest_high, highest_low) {
+            (Some(v), Some((h, a)), _) if v >= h => Check::TooHigh(a.to_string()),
+            (Some(v), _, Some((l, a))) if v <= l => Check::TooLow(a.to_string()),
+            _ => Check::Plausible,
+        }
+    }
+}
@@ -0,0 +1,375 @@
+/*
+Single entry point for all the puzzles of the workspace:
+the input is sent to the Solver of the requested day and part.
+ */
+
+mod bench;
+mod golden;
+mod ledger;
+mod registry;
+mod scaffold;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+
+use registry::Part;
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc run <day> <part> [input] [--param name=value] [--switch]... [--ledger] [--vocab file]");
+    eprintln!("       aoc list");
+    eprintln!("       aoc new <day> <part> [--from-a]");
+    eprintln!("       aoc check [day]");
+    eprintln!("       aoc answer <day> <part> <ok|high|low|wrong> <answer>");
+    eprintln!("       aoc answers [day]");
+    eprintln!("       aoc bench [day [part]] [--runs N] [--format table|json|csv] [--output file]");
+    eprintln!("       aoc bench <day> <part> --input file [--param name=value] [--switch]... [--runs N] ...");
+    eprintln!("example of day : 2, 02, 12");
+    eprintln!("part : A or B (or 1 or 2)");
+    eprintln!("input : puzzle input file, read from stdin if missing");
+    eprintln!("--param : runtime parameter declared by the solver, see aoc list");
+    eprintln!("--switch : a 0 or 1 parameter set to 1, e.g. --explain for --param explain=1");
+    eprintln!("--ledger : check the result against the answer ledger, done by default for the stored");
+    eprintln!("           puzzle inputs (input_A.txt, day_XX-P_case_A_input.txt...)");
+    eprintln!("--vocab : day 1 part B only, the digits and the \"word=digit\" tokens of the file, e.g. uno=1, dos=2");
+    eprintln!("-v, -vv, -vvv, -q : more or less diagnostics of the solvers, also set by AOC_LOG");
+    process::exit(1);
+}
+
+fn parse_day_part(day: &str, part: &str) -> (u32, Part) {
+    let Ok(day) = u32::from_str(day) else {
+        eprintln!("invalid day '{day}'");
+        usage();
+    };
+    match Part::from_str(part) {
+        Ok(p) => (day, p),
+        Err(e) => {
+            eprintln!("{e}");
+            usage();
+        }
+    }
+}
+
+fn load_ledger() -> ledger::Ledger {
+    match ledger::Ledger::load(&golden::workspace_root().join(ledger::LEDGER_FILE)) {
+        Ok(l) => l,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+// The day 1 part 2 tokens of a vocabulary file: the digits, and its words
+fn load_vocabulary(path: &str) -> day_01::scanner::Vocabulary {
+    let words = fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| day_01::scanner::Vocabulary::parse(&text).map_err(|e| e.to_string()));
+    match words {
+        Ok(words) => day_01::scanner::Vocabulary::digits_and(&words),
+        Err(e) => {
+            eprintln!("Can not read the vocabulary {path}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[String]) {
+    if args.len() < 2 {
+        usage();
+    }
+    let (day, part) = parse_day_part(&args[0], &args[1]);
+    let Some(puzzle) = registry::find(day, part) else {
+        eprintln!("No solver for day {day} part {part}");
+        process::exit(1);
+    };
+
+    // the optional input file, then the runtime parameters
+    let (input, rest) = match args.get(2) {
+        Some(a) if !a.starts_with("--") => (Some(a), &args[3..]),
+        _ => (None, &args[2..]),
+    };
+    let mut params = puzzle.default_params();
+    let mut ledger = input.is_some_and(|path| golden::is_puzzle_input(Path::new(path)));
+    let mut vocabulary = None;
+    let mut it = rest.iter();
+    while let Some(a) = it.next() {
+        let set = match a.as_str() {
+            "--param" => match it.next() {
+                Some(p) => params.set(p),
+                None => Err(format!("{a} expects a value")),
+            },
+            "--ledger" => {
+                ledger = true;
+                Ok(())
+            }
+            // part 1 only reads the ascii digits
+            "--vocab" if (day, part) != (1, Part::B) => Err(format!("{a} is only accepted by day 1 part B")),
+            "--vocab" => match it.next() {
+                Some(path) => {
+                    vocabulary = Some(load_vocabulary(path));
+                    Ok(())
+                }
+                None => Err(format!("{a} expects a file")),
+            },
+            _ => params.set_switch(a),
+        };
+        if let Err(e) = set {
+            eprintln!("{e}");
+            eprint!("Parameters of day {day} part {part}:\n{}", aoc_common::params::describe(puzzle.params));
+            process::exit(1);
+        }
+    }
+
+    let mut reader: Box<dyn BufRead> = match input {
+        None => Box::new(io::stdin().lock()),
+        Some(path) => match File::open(path) {
+            Ok(f) => Box::new(BufReader::new(f)),
+            Err(e) => {
+                eprintln!("Can not open input {path}: {e}");
+                process::exit(1);
+            }
+        },
+    };
+
+    let solved = match &vocabulary {
+        Some(v) => day_01::run_with(&mut reader, &params, v),
+        None => (puzzle.run)(&mut reader, &params),
+    };
+    let result = match solved {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("input error: {e}");
+            process::exit(1);
+        }
+    };
+    println!("{result}");
+
+    // only meaningful for the actual puzzle input, not the samples
+    if ledger {
+        let check = load_ledger().check(day, part, &result.to_string());
+        if check != ledger::Check::Unknown {
+            eprintln!("answer ledger: {check}");
+        }
+    }
+}
+
+// Records an answer submitted on the site
+fn answer(args: &[String]) {
+    let [day, part, verdict, answer] = args else {
+        usage();
+    };
+    let (day, part) = parse_day_part(day, part);
+    let verdict = match ledger::Verdict::from_str(verdict) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("{e}");
+            usage();
+        }
+    };
+
+    let mut ledger = load_ledger();
+    let known = ledger.check(day, part, answer);
+    if let Err(e) = ledger.record(day, part, verdict, answer) {
+        eprintln!("Not recorded: {e}");
+        process::exit(1);
+    }
+    if known.is_wrong() && verdict == ledger::Verdict::Accepted {
+        eprintln!("Warning, the ledger said: {known}");
+    }
+    if let Err(e) = ledger.save() {
+        eprintln!("Can not write the answer ledger: {e}");
+        process::exit(1);
+    }
+}
+
+// Lists the recorded answers
+fn answers(args: &[String]) {
+    let day = match args {
+        [] => None,
+        [d] => match u32::from_str(d) {
+            Ok(d) => Some(d),
+            Err(_) => {
+                eprintln!("invalid day '{d}'");
+                usage();
+            }
+        },
+        _ => usage(),
+    };
+    for e in load_ledger().entries.iter().filter(|e| day.is_none_or(|d| d == e.day)) {
+        println!("day {:2} part {} {:>5} {}", e.day, e.part, e.verdict, e.answer);
+    }
+}
+
+// Scaffolding of the crate of a new puzzle
+fn new(args: &[String]) {
+    let (day, part, from_a) = match args {
+        [day, part] => (day, part, false),
+        [day, part, flag] if flag == "--from-a" => (day, part, true),
+        _ => usage(),
+    };
+    let (day, part) = parse_day_part(day, part);
+
+    match scaffold::new_day(&golden::workspace_root(), day, part, from_a) {
+        Ok(dir) => println!("Created {}, registered as day {day} part {part}", dir.display()),
+        Err(e) => {
+            eprintln!("Can not create day {day} part {part}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+// Golden-file regression check of all the stored expected outputs
+fn check(args: &[String]) {
+    let day = match args {
+        [] => None,
+        [d] => match u32::from_str(d) {
+            Ok(d) => Some(d),
+            Err(_) => {
+                eprintln!("invalid day '{d}'");
+                usage();
+            }
+        },
+        _ => usage(),
+    };
+
+    let root = golden::workspace_root();
+    let outcomes = match golden::run_all(&root, day) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("Can not read the case files: {e}");
+            process::exit(1);
+        }
+    };
+    golden::print_table(&root, &outcomes);
+    if outcomes.iter().any(|o| o.is_regression()) {
+        process::exit(1);
+    }
+}
+
+// Timing of the solvers over the stored inputs, or over another input
+fn bench(args: &[String]) {
+    let mut day = None;
+    let mut part = None;
+    let mut runs = 10;
+    let mut format = bench::Format::Table;
+    let mut output = None;
+    let mut input = None;
+    // runtime parameters, for the given input only
+    let mut params = Vec::new();
+
+    let mut it = args.iter();
+    while let Some(a) = it.next() {
+        match a.as_str() {
+            "--runs" => match it.next().map(|n| usize::from_str(n)) {
+                Some(Ok(n)) if n > 0 => runs = n,
+                _ => {
+                    eprintln!("--runs expects a number of runs");
+                    usage();
+                }
+            },
+            "--format" => match it.next().map(|f| bench::Format::from_str(f)) {
+                Some(Ok(f)) => format = f,
+                Some(Err(e)) => {
+                    eprintln!("{e}");
+                    usage();
+                }
+                None => usage(),
+            },
+            "--output" => match it.next() {
+                Some(f) => output = Some(f),
+                None => usage(),
+            },
+            "--input" => match it.next() {
+                Some(f) => input = Some(f),
+                None => usage(),
+            },
+            "--param" => match it.next() {
+                Some(p) => params.extend([a.clone(), p.clone()]),
+                None => usage(),
+            },
+            _ if a.starts_with("--") => params.push(a.clone()),
+            _ if day.is_none() => match u32::from_str(a) {
+                Ok(d) => day = Some(d),
+                Err(_) => {
+                    eprintln!("invalid day '{a}'");
+                    usage();
+                }
+            },
+            _ if part.is_none() => match Part::from_str(a) {
+                Ok(p) => part = Some(p),
+                Err(e) => {
+                    eprintln!("{e}");
+                    usage();
+                }
+            },
+            _ => usage(),
+        }
+    }
+
+    let results = match (input, day, part) {
+        (Some(input), Some(day), Some(part)) => match bench::bench_input(day, part, input.as_ref(), &params, runs) {
+            Ok(r) => vec![r],
+            Err(e) => {
+                eprintln!("day {day} part {part} {input}: {e}");
+                process::exit(1);
+            }
+        },
+        (Some(_), _, _) => {
+            eprintln!("--input needs the day and the part");
+            usage();
+        }
+        (None, _, _) if !params.is_empty() => {
+            eprintln!("the parameters are only for a given --input");
+            usage();
+        }
+        (None, _, _) => match bench::bench_all(&golden::workspace_root(), day, part, runs) {
+            Ok(r) => r,
+            Err(e) => {
+                eprintln!("Can not read the case files: {e}");
+                process::exit(1);
+            }
+        },
+    };
+    let report = bench::report(&results, format);
+    match output {
+        None => print!("{report}"),
+        Some(path) => {
+            if let Err(e) = fs::write(path, report) {
+                eprintln!("Can not write {path}: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn list() {
+    for p in registry::PUZZLES {
+        println!("day {:2} part {}", p.day, p.part);
+        print!("{}", aoc_common::params::describe(p.params));
+    }
+}
+
+fn main() {
+    // the log flags are accepted anywhere in the arguments
+    let args = match aoc_common::log::init(env::args().skip(1).collect()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            usage();
+        }
+    };
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("list") => list(),
+        Some("new") => new(&args[1..]),
+        Some("check") => check(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("answer") => answer(&args[1..]),
+        Some("answers") => answers(&args[1..]),
+        _ => usage(),
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * Table of all the puzzles solved in this workspace, with the
+ * entry point of their Solver.
+ */
+
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use aoc_common::{Answer, Param, Params};
+
+#[derive(Clone,Copy,Debug,PartialEq,Eq,PartialOrd,Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    // Accept the "A/B" naming of the directories as well as
+    // the "1/2" naming of the puzzle descriptions.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "a" | "1" => Ok(Part::A),
+            "B" | "b" | "2" => Ok(Part::B),
+            _ => Err(format!("invalid part '{s}', expected A or B")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => f.pad("A"),
+            Part::B => f.pad("B"),
+        }
+    }
+}
+
+// Read all the puzzle input and return its answer.
+// The solvers of both parts find the part to solve in the parameters.
+pub type RunFn = fn(&mut dyn BufRead, &Params) -> io::Result<Answer>;
+
+pub struct Puzzle {
+    pub day: u32,
+    pub part: Part,
+    pub run: RunFn,
+    // runtime parameters accepted by the solver
+    pub params: &'static [Param],
+}
+
+impl Puzzle {
+    // The parameters for this part, the other ones left to their default
+    pub fn default_params(&self) -> Params {
+        let mut params = Params::new(self.params);
+        params.set_part(&self.part.to_string()).expect("A or B is a valid part");
+        params
+    }
+}
+
+use Part::*;
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle { day: 1, part: A, run: day_01::run, params: day_01::PARAMS },
+    Puzzle { day: 1, part: B, run: day_01::run, params: day_01::PARAMS },
+    Puzzle { day: 2, part: A, run: day_02::part_a::run, params: day_02::part_a::PARAMS },
+    Puzzle { day: 2, part: B, run: |r, _| day_02::part_b::run(r), params: &[] },
+    Puzzle { day: 3, part: A, run: day_03_a::run, params: day_03_a::PARAMS },
+    Puzzle { day: 3, part: B, run: day_03_b::run, params: day_03_b::PARAMS },
+    Puzzle { day: 4, part: A, run: |r, _| day_04_a::run(r), params: &[] },
+    Puzzle { day: 4, part: B, run: |r, _| day_04_b::run(r), params: &[] },
+    Puzzle { day: 5, part: A, run: |r, _| day_05_a::run(r), params: &[] },
+    Puzzle { day: 5, part: B, run: |r, _| day_05_b::run(r), params: &[] },
+    Puzzle { day: 6, part: A, run: |r, _| day_06::part_a::run(r), params: &[] },
+    Puzzle { day: 6, part: B, run: |r, _| day_06::part_b::run(r), params: &[] },
+    Puzzle { day: 7, part: A, run: |r, _| day_07_a::run(r), params: &[] },
+    Puzzle { day: 7, part: B, run: |r, _| day_07_b::run(r), params: &[] },
+    Puzzle { day: 8, part: A, run: |r, _| day_08::part_a::run(r), params: &[] },
+    Puzzle { day: 8, part: B, run: |r, _| day_08::part_b::run(r), params: &[] },
+    Puzzle { day: 9, part: A, run: day_09_ab::run, params: day_09_ab::PARAMS },
+    Puzzle { day: 9, part: B, run: day_09_ab::run, params: day_09_ab::PARAMS },
+    Puzzle { day: 10, part: A, run: |r, _| day_10_a::run(r), params: &[] },
+    Puzzle { day: 10, part: B, run: |r, _| day_10_b::run(r), params: &[] },
+    Puzzle { day: 11, part: A, run: day_11_ab::run, params: day_11_ab::PARAMS },
+    Puzzle { day: 11, part: B, run: day_11_ab::run, params: day_11_ab::PARAMS },
+    Puzzle { day: 12, part: A, run: |r, _| day_12_a::run(r), params: &[] },
+    Puzzle { day: 12, part: B, run: day_12_b::run, params: day_12_b::PARAMS },
+    Puzzle { day: 13, part: A, run: |r, _| day_13_a::run(r), params: &[] },
+    Puzzle { day: 13, part: B, run: |r, _| day_13_b::run(r), params: &[] },
+    Puzzle { day: 14, part: A, run: day_14_ab::run, params: day_14_ab::PARAMS },
+    Puzzle { day: 14, part: B, run: day_14_ab::run, params: day_14_ab::PARAMS },
+    Puzzle { day: 15, part: A, run: |r, _| day_15_a::run(r), params: &[] },
+    Puzzle { day: 15, part: B, run: |r, _| day_15_b::run(r), params: &[] },
+    Puzzle { day: 16, part: A, run: day_16_ab::run, params: day_16_ab::PARAMS },
+    Puzzle { day: 16, part: B, run: day_16_ab::run, params: day_16_ab::PARAMS },
+    Puzzle { day: 17, part: A, run: |r, _| day_17::part_a::run(r), params: &[] },
+    Puzzle { day: 17, part: B, run: |r, _| day_17::part_b::run(r), params: &[] },
+    Puzzle { day: 18, part: A, run: |r, _| day_18::part_a::run(r), params: &[] },
+    Puzzle { day: 18, part: B, run: |r, _| day_18::part_b::run(r), params: &[] },
+    Puzzle { day: 19, part: A, run: |r, _| day_19_a::run(r), params: &[] },
+    Puzzle { day: 19, part: B, run: |r, _| day_19_b::run(r), params: &[] },
+    Puzzle { day: 20, part: A, run: |r, _| day_20_a::run(r), params: &[] },
+    Puzzle { day: 20, part: B, run: |r, _| day_20_b::run(r), params: &[] },
+    Puzzle { day: 21, part: A, run: day_21_a::run, params: day_21_a::PARAMS },
+    Puzzle { day: 22, part: A, run: |r, _| day_22_a::run(r), params: &[] },
+    Puzzle { day: 22, part: B, run: |r, _| day_22_b::run(r), params: &[] },
+    Puzzle { day: 24, part: A, run: day_24_a::run, params: day_24_a::PARAMS },
+    Puzzle { day: 25, part: A, run: |r, _| day_25_a::run(r), params: &[] },
+];
+
+pub fn find(day: u32, part: Part) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.day == day && p.part == part)
+}
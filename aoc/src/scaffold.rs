@@ -0,0 +1,155 @@
+/*
+Creation of the crate of a new puzzle from template.rs, registered in
+the workspace and in the runner, with an empty test case to fill.
+ */
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::registry::{self, Part};
+
+const TEMPLATE: &str = "template.rs";
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("can not read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|e| format!("can not write {}: {e}", path.display()))
+}
+
+// Inserts a new line in a sorted block of lines of `text`: `is_block` selects
+// the lines of the block, the new line goes before the first one for which
+// `is_after` is true (or after the last one).
+fn insert_line(
+    text: &str,
+    new_line: &str,
+    is_block: impl Fn(&str) -> bool,
+    is_after: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let block: Vec<usize> = (0..lines.len()).filter(|&i| is_block(lines[i])).collect();
+    let at = match block.iter().find(|&&i| is_after(lines[i])) {
+        Some(&i) => i,
+        None => block.last()? + 1,
+    };
+
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(new_line);
+    out.extend_from_slice(&lines[at..]);
+    Some(out.join("\n") + "\n")
+}
+
+// "    Puzzle { day: 12, part: B, ..." => (12, B)
+fn registry_entry(line: &str) -> Option<(u32, Part)> {
+    let rest = line.trim().strip_prefix("Puzzle { day: ")?;
+    let (day, rest) = rest.split_once(", part: ")?;
+    let (part, _) = rest.split_once(',')?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+fn cargo_toml(name: &str, lib: &str) -> String {
+    format!(
+        "[package]
+name = \"{name}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[lib]
+name = \"{lib}\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_common = {{ path = \"../aoc_common\" }}
+
+[lints]
+workspace = true
+"
+    )
+}
+
+// Creates day_XX-P. For part B, the solver can start as a copy of the
+// part A one instead of the template.
+pub fn new_day(root: &Path, day: u32, part: Part, from_a: bool) -> Result<PathBuf, String> {
+    let name = format!("day_{day:02}-{part}");
+    let lib = format!("day_{day:02}_{}", part.to_string().to_lowercase());
+    let dir = root.join(&name);
+
+    if registry::find(day, part).is_some() {
+        return Err(format!("day {day} part {part} already has a solver"));
+    }
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    if from_a && part != Part::B {
+        return Err("only part B can start from the part A solver".to_string());
+    }
+
+    let (manifest, solver) = if from_a {
+        let a_name = format!("day_{day:02}-A");
+        let a_dir = root.join(&a_name);
+        let manifest = read(&a_dir.join("Cargo.toml"))?
+            .replace(&format!("name = \"{a_name}\""), &format!("name = \"{name}\""))
+            .replace(&format!("name = \"day_{day:02}_a\""), &format!("name = \"{lib}\""));
+        (manifest, read(&a_dir.join("src/lib.rs"))?)
+    } else {
+        let solver = read(&root.join(TEMPLATE))?.replace("<DAY>", &day.to_string());
+        (cargo_toml(&name, &lib), solver)
+    };
+
+    // a solver copied from part A may already take runtime parameters
+    let (main, entry) = if solver.contains("pub const PARAMS") {
+        (
+            format!("aoc_common::main_with_params({lib}::PARAMS, {lib}::run);"),
+            format!("run: {lib}::run, params: {lib}::PARAMS"),
+        )
+    } else {
+        (format!("aoc_common::main({lib}::run);"), format!("run: |r, _| {lib}::run(r), params: &[]"))
+    };
+
+    // the crate itself
+    fs::create_dir_all(dir.join("src")).map_err(|e| format!("can not create {}: {e}", dir.display()))?;
+    write(&dir.join("Cargo.toml"), &manifest)?;
+    write(&dir.join("src/lib.rs"), &solver)?;
+    write(&dir.join("src/main.rs"), &format!("fn main() {{\n    {main}\n}}\n"))?;
+    // empty test case, for the golden-file check
+    write(&dir.join(format!("{name}_case_1_input.txt")), "")?;
+    write(&dir.join(format!("{name}_case_1_output.txt")), "")?;
+
+    // member of the workspace
+    let path = root.join("Cargo.toml");
+    let workspace = insert_line(
+        &read(&path)?,
+        &format!("    \"{name}\","),
+        |l| l.starts_with("    \"day_"),
+        |l| l.trim().trim_matches(|c| c == '"' || c == ',') > name.as_str(),
+    )
+    .ok_or("no day in the workspace members")?;
+    write(&path, &workspace)?;
+
+    // dependency of the runner
+    let path = root.join("aoc/Cargo.toml");
+    let runner = insert_line(
+        &read(&path)?,
+        &format!("{name} = {{ path = \"../{name}\" }}"),
+        |l| l.starts_with("day_"),
+        |l| l.split(' ').next().is_some_and(|d| d > name.as_str()),
+    )
+    .ok_or("no day in the dependencies of the runner")?;
+    write(&path, &runner)?;
+
+    // entry of the registry
+    let path = root.join("aoc/src/registry.rs");
+    let registry = insert_line(
+        &read(&path)?,
+        &format!("    Puzzle {{ day: {day}, part: {part}, {entry} }},"),
+        |l| registry_entry(l).is_some(),
+        |l| registry_entry(l).is_some_and(|e| e > (day, part)),
+    )
+    .ok_or("no puzzle in the registry")?;
+    write(&path, &registry)?;
+
+    Ok(dir)
+}
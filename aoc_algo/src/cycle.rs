@@ -0,0 +1,189 @@
+/*
+Cycle detection, for the puzzles asking for the state after a number of
+steps far too large to simulate, like a billion spin cycles.
+
+The states are iterated with the step function until one repeats:
+  find_cycle(initial, step, max_steps)      the cycle, with all the states
+  cycle.state_at(1_000_000_000)             the state after that many steps
+  state_after(initial, n, step)             the same in one call
+The _by_key() variants hash a key of the states instead of the whole
+states. The key can be a cheap and lossy fingerprint: the states are
+still compared when their keys are equal, so a collision only costs a
+comparison and never gives a wrong cycle.
+
+periodic_tail() finds the period of an observed sequence instead, when
+the whole state is too large to repeat but some of its values do.
+ */
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<S> {
+    // the states from the initial one, up to the last one before the repeat
+    pub states: Vec<S>,
+    // index of the first state of the cycle, and number of states in it
+    pub start: usize,
+    pub len: usize,
+}
+
+impl<S> Cycle<S> {
+    // Index in the states of the state after n steps
+    pub fn index_at(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    // The state after n steps
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.index_at(n)]
+    }
+}
+
+// The cycle reached from initial, None if no state repeats within max_steps
+pub fn find_cycle<S>(initial: S, step: impl FnMut(&S) -> S, max_steps: usize) -> Option<Cycle<S>>
+where
+    S: Clone + Eq + Hash,
+{
+    find_cycle_by_key(initial, step, S::clone, max_steps)
+}
+
+pub fn find_cycle_by_key<S, K>(
+    initial: S,
+    step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+    max_steps: usize,
+) -> Option<Cycle<S>>
+where
+    S: PartialEq,
+    K: Eq + Hash,
+{
+    iterate(initial, step, key, max_steps).ok()
+}
+
+// The state after n steps, found in the cycle when the states repeat before
+pub fn state_after<S>(initial: S, n: usize, step: impl FnMut(&S) -> S) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    state_after_by_key(initial, n, step, S::clone)
+}
+
+pub fn state_after_by_key<S, K>(initial: S, n: usize, step: impl FnMut(&S) -> S, key: impl FnMut(&S) -> K) -> S
+where
+    S: PartialEq,
+    K: Eq + Hash,
+{
+    match iterate(initial, step, key, n) {
+        Ok(mut c) => {
+            let i = c.index_at(n);
+            c.states.swap_remove(i)
+        }
+        Err(last) => last,
+    }
+}
+
+// Steps until a state repeats, or else returns the state after max_steps
+fn iterate<S, K>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    max_steps: usize,
+) -> Result<Cycle<S>, S>
+where
+    S: PartialEq,
+    K: Eq + Hash,
+{
+    // the indices of the states by key, several on a collision
+    let mut seen = HashMap::<K, Vec<usize>>::new();
+    let mut states = Vec::new();
+    let mut s = initial;
+    let mut n = 0;
+    loop {
+        let indices = seen.entry(key(&s)).or_default();
+        if let Some(&start) = indices.iter().find(|&&i| states[i] == s) {
+            return Ok(Cycle { states, start, len: n - start });
+        }
+        if n == max_steps {
+            return Err(s);
+        }
+        indices.push(n);
+        let next = step(&s);
+        states.push(s);
+        s = next;
+        n += 1;
+    }
+}
+
+// Smallest period of the end of a sequence: the values repeat with that
+// period from a start index, at most max_start, to the end of the
+// sequence, and the periodic part covers at least two periods.
+// Returns the cycle as (start, period).
+pub fn periodic_tail<T: PartialEq>(seq: &[T], max_start: usize) -> Option<(usize, usize)> {
+    let n = seq.len();
+    (1..=n / 2).find_map(|period| {
+        // first index from which seq[i] == seq[i + period] up to the end
+        let start = (0..n - period).rev().find(|&i| seq[i] != seq[i + period]).map_or(0, |i| i + 1);
+        (start <= max_start && n - start >= 2 * period).then_some((start, period))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then 3 4 5 6 7 over and over
+    fn step(&s: &u32) -> u32 {
+        if s == 7 { 3 } else { s + 1 }
+    }
+
+    #[test]
+    fn cycle_after_a_tail() {
+        let c = find_cycle(0, step, 100).unwrap();
+        assert_eq!((c.start, c.len), (3, 5));
+        assert_eq!(c.states, [0, 1, 2, 3, 4, 5, 6, 7]);
+        for n in 0..40 {
+            let mut s = 0;
+            for _ in 0..n {
+                s = step(&s);
+            }
+            assert_eq!(*c.state_at(n), s, "after {n} steps");
+        }
+        assert_eq!(*c.state_at(1_000_000_000), 3 + (1_000_000_000 - 3) % 5);
+    }
+
+    #[test]
+    fn cycle_from_the_start() {
+        let c = find_cycle(0u32, |&s| (s + 1) % 4, 100).unwrap();
+        assert_eq!((c.start, c.len), (0, 4));
+        assert_eq!(*c.state_at(10), 2);
+    }
+
+    #[test]
+    fn no_repeat_within_max_steps() {
+        // the first repeat is at step 8
+        assert!(find_cycle(0, step, 7).is_none());
+        assert!(find_cycle(0, step, 8).is_some());
+        assert_eq!(state_after(0, 5, step), 5);
+        assert_eq!(state_after(0, 12, step), 7);
+    }
+
+    #[test]
+    fn colliding_keys() {
+        // every state with the same key: found by comparing the states
+        let c = find_cycle_by_key(0, step, |_| 0, 100).unwrap();
+        assert_eq!((c.start, c.len), (3, 5));
+        assert_eq!(state_after_by_key(0, 1_000, step, |s| s % 2), 3 + (1_000 - 3) % 5);
+    }
+
+    #[test]
+    fn periodic_sequence_tail() {
+        let seq = [9, 8, 1, 2, 3, 1, 2, 3, 1, 2, 3];
+        assert_eq!(periodic_tail(&seq, 5), Some((2, 3)));
+        assert_eq!(periodic_tail(&seq, 1), None);
+        assert_eq!(periodic_tail(&[1, 2, 3, 4], 4), None);
+    }
+}
@@ -0,0 +1,409 @@
+/*
+Sets of integers kept as ranges, for the puzzles where the values are far
+too many to handle one by one: seeds, part ratings, free spans of a row.
+  IntervalSet    sorted, disjoint and non-adjacent ranges: union,
+                 intersection, difference, split_at, count
+  PiecewiseMap   a map shifting each of its source ranges by an offset,
+                 the identity elsewhere: apply to a value or a whole set,
+                 compose with the next map, invert
+Everything stays exact whatever the size of the ranges, the cost only
+depends on their number.
+ */
+
+use std::ops::Range;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    // sorted, not empty, and separated by at least one missing value
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    // Normalizes any ranges, overlapping or empty ones included
+    pub fn from_ranges(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        let mut sorted: Vec<Range<T>> = ranges.into_iter().filter(|r| r.start < r.end).collect();
+        sorted.sort_unstable_by_key(|r| r.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(sorted.len());
+        for r in sorted {
+            match merged.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => merged.push(r),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> + '_ {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        // the first range ending after x is the only one which can contain it
+        let i = self.ranges.partition_point(|r| r.end <= x);
+        self.ranges.get(i).is_some_and(|r| r.start <= x)
+    }
+
+    pub fn insert(&mut self, r: Range<T>) {
+        *self = self.union(&Self::from(r));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            // the range ending first can't meet the next ones of the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    // The values of self not in other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for r in &self.ranges {
+            let mut start = r.start;
+            // skip the ranges of other ending before this one
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < r.end {
+                if start < other.ranges[k].start {
+                    ranges.push(start..other.ranges[k].start);
+                }
+                start = start.max(other.ranges[k].end);
+                k += 1;
+            }
+            if start < r.end {
+                ranges.push(start..r.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    // The values below x, and the ones from x
+    pub fn split_at(&self, x: T) -> (Self, Self) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+        for r in &self.ranges {
+            if r.end <= x {
+                below.push(r.clone());
+            } else if r.start >= x {
+                above.push(r.clone());
+            } else {
+                below.push(r.start..x);
+                above.push(x..r.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    // Number of values in the set
+    pub fn count(&self) -> i64
+    where
+        T: Into<i64>,
+    {
+        self.ranges.iter().map(|r| r.end.into() - r.start.into()).sum()
+    }
+}
+
+impl IntervalSet<i64> {
+    // All the values moved by offset
+    pub fn shifted(&self, offset: i64) -> Self {
+        Self { ranges: self.ranges.iter().map(|r| r.start + offset..r.end + offset).collect() }
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(r: Range<T>) -> Self {
+        Self::from_ranges([r])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        Self::from_ranges(ranges)
+    }
+}
+
+// Piecewise-linear map of slope 1: the values of each source range are
+// moved by its offset, the values outside all of them are unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    // (source range, offset), sorted and disjoint, no zero offset
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl PiecewiseMap {
+    // The identity
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    // Builds from pieces, which must not overlap
+    pub fn from_pieces(pieces: impl IntoIterator<Item = (Range<i64>, i64)>) -> Self {
+        let mut m = Self::new();
+        for (r, offset) in pieces {
+            m.insert(r, offset);
+        }
+        m
+    }
+
+    // Maps the source range by offset. Panics if it overlaps another one.
+    pub fn insert(&mut self, source: Range<i64>, offset: i64) {
+        if source.is_empty() || offset == 0 {
+            return;
+        }
+        let i = self.pieces.partition_point(|(r, _)| r.end <= source.start);
+        if let Some((r, _)) = self.pieces.get(i) {
+            assert!(r.start >= source.end, "overlapping ranges {r:?} and {source:?} in a map");
+        }
+        self.pieces.insert(i, (source, offset));
+    }
+
+    pub fn pieces(&self) -> &[(Range<i64>, i64)] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, x: i64) -> i64 {
+        let i = self.pieces.partition_point(|(r, _)| r.end <= x);
+        match self.pieces.get(i) {
+            Some((r, offset)) if r.start <= x => x + offset,
+            _ => x,
+        }
+    }
+
+    // The source ranges of the pieces
+    pub fn domain(&self) -> IntervalSet<i64> {
+        self.pieces.iter().map(|(r, _)| r.clone()).collect()
+    }
+
+    // The image of a whole set
+    pub fn apply_set(&self, set: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let moved = self.pieces.iter().flat_map(|(r, offset)| {
+            set.intersection(&IntervalSet::from(r.clone())).shifted(*offset).ranges
+        });
+        let unchanged = set.difference(&self.domain()).ranges;
+        IntervalSet::from_ranges(moved.chain(unchanged))
+    }
+
+    // The map x -> next(self(x))
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = Vec::new();
+        for (r, offset) in &self.pieces {
+            // the image of this piece, split by the pieces of next
+            let image = IntervalSet::from(r.start + offset..r.end + offset);
+            for (r2, offset2) in &next.pieces {
+                for part in image.intersection(&IntervalSet::from(r2.clone())).iter() {
+                    pieces.push((part.start - offset..part.end - offset, offset + offset2));
+                }
+            }
+            for part in image.difference(&next.domain()).iter() {
+                pieces.push((part.start - offset..part.end - offset, *offset));
+            }
+        }
+        // next applies alone where self is the identity
+        let gaps = self.domain();
+        for (r2, offset2) in &next.pieces {
+            for part in IntervalSet::from(r2.clone()).difference(&gaps).iter() {
+                pieces.push((part.clone(), *offset2));
+            }
+        }
+        pieces.sort_unstable_by_key(|(r, _)| r.start);
+        Self::normalized(pieces)
+    }
+
+    // The inverse map, None when two values have the same image
+    pub fn invert(&self) -> Option<PiecewiseMap> {
+        let images = self.pieces.iter().map(|(r, offset)| (r.start + offset..r.end + offset, -offset));
+        let inverse = Self { pieces: sorted_disjoint(images.collect())? };
+        // the values moved away must be exactly the ones moved in, the
+        // others are their own image
+        (inverse.domain() == self.domain()).then_some(inverse)
+    }
+
+    // Drops the zero offsets and merges the adjacent pieces of same offset
+    fn normalized(sorted: Vec<(Range<i64>, i64)>) -> Self {
+        let mut pieces: Vec<(Range<i64>, i64)> = Vec::with_capacity(sorted.len());
+        for (r, offset) in sorted.into_iter().filter(|(r, offset)| *offset != 0 && !r.is_empty()) {
+            match pieces.last_mut() {
+                Some((last, o)) if last.end == r.start && *o == offset => last.end = r.end,
+                _ => pieces.push((r, offset)),
+            }
+        }
+        Self { pieces }
+    }
+}
+
+fn sorted_disjoint(mut pieces: Vec<(Range<i64>, i64)>) -> Option<Vec<(Range<i64>, i64)>> {
+    pieces.sort_unstable_by_key(|(r, _)| r.start);
+    pieces.windows(2).all(|w| w[0].0.end <= w[1].0.start).then_some(pieces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        IntervalSet::from_ranges(ranges.iter().cloned())
+    }
+
+    // The values of the set among -20..40, one by one
+    fn values(s: &IntervalSet<i64>) -> Vec<i64> {
+        (-20..40).filter(|&x| s.contains(x)).collect()
+    }
+
+    #[test]
+    fn normalized_ranges() {
+        let s = set(&[5..8, 0..2, 2..3, 7..10, 4..4]);
+        assert_eq!(s.ranges(), [0..3, 5..10]);
+        assert_eq!(s.count(), 8);
+        assert_eq!(s.min(), Some(0));
+        assert!(s.contains(9) && !s.contains(10) && !s.contains(3));
+        assert!(IntervalSet::from(3..3).is_empty());
+    }
+
+    #[test]
+    fn intersection_at_range_edges() {
+        let a = set(&[0..5, 10..15]);
+        // touching is not sharing: the ends are excluded
+        assert!(a.intersection(&IntervalSet::from(5..10)).is_empty());
+        assert_eq!(a.intersection(&IntervalSet::from(4..11)).ranges(), [4..5, 10..11]);
+        assert_eq!(a.intersection(&set(&[0..1, 14..15])).ranges(), [0..1, 14..15]);
+        assert_eq!(a.intersection(&a), a);
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+        // one range across several
+        assert_eq!(IntervalSet::from(-5..30).intersection(&a), a);
+    }
+
+    #[test]
+    fn difference_at_range_edges() {
+        let a = set(&[0..5, 10..15]);
+        assert_eq!(a.difference(&IntervalSet::from(5..10)), a);
+        assert_eq!(a.difference(&IntervalSet::from(4..11)).ranges(), [0..4, 11..15]);
+        assert_eq!(a.difference(&set(&[0..1, 14..15])).ranges(), [1..5, 10..14]);
+        assert_eq!(a.difference(&set(&[1..2, 3..4])).ranges(), [0..1, 2..3, 4..5, 10..15]);
+        assert!(a.difference(&IntervalSet::from(0..15)).is_empty());
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(IntervalSet::new().difference(&a), IntervalSet::new());
+    }
+
+    #[test]
+    fn set_operations_agree_with_values() {
+        let sets = [set(&[0..5, 10..15]), set(&[-3..1, 4..11, 14..20]), IntervalSet::from(5..10), IntervalSet::new()];
+        for a in &sets {
+            for b in &sets {
+                let (va, vb) = (values(a), values(b));
+                let inter: Vec<i64> = va.iter().copied().filter(|x| vb.contains(x)).collect();
+                let diff: Vec<i64> = va.iter().copied().filter(|x| !vb.contains(x)).collect();
+                assert_eq!(values(&a.intersection(b)), inter);
+                assert_eq!(values(&a.difference(b)), diff);
+                assert_eq!(a.union(b).count(), (va.len() + vb.len() - inter.len()) as i64);
+            }
+        }
+    }
+
+    #[test]
+    fn split_at_edges() {
+        let a = set(&[0..5, 10..15]);
+        assert_eq!(a.split_at(5), (IntervalSet::from(0..5), IntervalSet::from(10..15)));
+        assert_eq!(a.split_at(10), (IntervalSet::from(0..5), IntervalSet::from(10..15)));
+        assert_eq!(a.split_at(12), (set(&[0..5, 10..12]), IntervalSet::from(12..15)));
+        assert_eq!(a.split_at(0), (IntervalSet::new(), a.clone()));
+    }
+
+    // Two overlapping moves: 0..10 onto 5..15, and 10..15 onto 0..5
+    fn swap() -> PiecewiseMap {
+        PiecewiseMap::from_pieces([(0..10, 5), (10..15, -10)])
+    }
+
+    #[test]
+    fn apply_values_and_sets() {
+        let m = swap();
+        assert_eq!([-1, 0, 9, 10, 14, 15].map(|x| m.apply(x)), [-1, 5, 14, 0, 4, 15]);
+        assert_eq!(m.apply_set(&IntervalSet::from(8..12)).ranges(), [0..2, 13..15]);
+        assert_eq!(m.apply_set(&IntervalSet::from(-2..20)), IntervalSet::from(-2..20));
+    }
+
+    #[test]
+    fn then_on_overlapping_images() {
+        // the images of the first map overlap the pieces of the second one,
+        // and the second moves values the first leaves unchanged
+        let maps = [
+            swap(),
+            PiecewiseMap::from_pieces([(3..7, 20), (12..30, -12)]),
+            PiecewiseMap::from_pieces([(0..20, 1)]),
+            PiecewiseMap::new(),
+        ];
+        for a in &maps {
+            for b in &maps {
+                let c = a.then(b);
+                for x in -20..40 {
+                    assert_eq!(c.apply(x), b.apply(a.apply(x)), "{a:?} then {b:?} at {x}");
+                }
+                assert!(c.pieces().iter().all(|(_, offset)| *offset != 0));
+            }
+        }
+        // moving back the moved values is the identity, but back still
+        // moves the values forth left in place
+        let forth = PiecewiseMap::from_pieces([(0..10, 3)]);
+        let back = PiecewiseMap::from_pieces([(3..13, -3)]);
+        assert_eq!(forth.then(&back), PiecewiseMap::from_pieces([(10..13, -3)]));
+    }
+
+    #[test]
+    fn invert_bijections() {
+        let m = swap();
+        let inverse = m.invert().unwrap();
+        for x in -20..40 {
+            assert_eq!(inverse.apply(m.apply(x)), x);
+        }
+        assert_eq!(m.then(&inverse), PiecewiseMap::new());
+        assert_eq!(PiecewiseMap::new().invert(), Some(PiecewiseMap::new()));
+    }
+
+    #[test]
+    fn invert_non_injective_maps() {
+        // 0..5 onto 10..15, where 10..15 stay
+        assert_eq!(PiecewiseMap::from_pieces([(0..5, 10)]).invert(), None);
+        // two pieces with overlapping images
+        assert_eq!(PiecewiseMap::from_pieces([(0..5, 10), (20..25, -8)]).invert(), None);
+        // a single value sent onto an unchanged one
+        assert_eq!(PiecewiseMap::from_pieces([(0..10, 5), (10..15, -9)]).invert(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlapping ranges")]
+    fn overlapping_sources() {
+        PiecewiseMap::from_pieces([(0..10, 1), (9..12, 2)]);
+    }
+}
@@ -0,0 +1,16 @@
+/*
+Algorithms shared by the puzzles, over the states and values given by
+the solvers rather than a particular map or input.
+ */
+
+pub mod cycle;
+pub mod interval;
+pub mod memo;
+pub mod number;
+pub mod search;
+
+pub use cycle::{find_cycle, state_after, Cycle};
+pub use interval::{IntervalSet, PiecewiseMap};
+pub use memo::{Equivalent, Memo};
+pub use number::{crt, first_common, gcd, lcm, lcm_all, Progression};
+pub use search::{astar, bfs, bfs_all, dijkstra, dijkstra_all, Path, Reached};
@@ -0,0 +1,284 @@
+/*
+Memoization of the expensive functions, mostly the recursive counters
+called again and again with the same arguments.
+  Memo::new()                     keeps every result
+  Memo::with_limit(n)             evicts the oldest results past n entries
+  memo.call(&key, f)              the result of f(memo, key), computed once
+  memo.get(&key), insert(k, v)    the same by hand
+  memo.stats()                    hits, misses, evictions and size
+The lookups take a borrowed form of the key, like a &str for a String
+key: only the missing entries build an owned key. For a composite key,
+the borrowed form is a struct of references hashing like the owned key
+and implementing Equivalent<K>: the comparison and the owned copy.
+
+call() hands the memo to f, which can then call() it again for its own
+recursive calls, and returns a reference to the result instead of a
+copy.
+ */
+
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+// A borrowed form of the key K, hashing like it
+pub trait Equivalent<K> {
+    fn equivalent(&self, key: &K) -> bool;
+    fn to_key(&self) -> K;
+}
+
+// Any key for itself, and the usual borrowed forms (str, slices...)
+impl<Q: Eq + ToOwned + ?Sized> Equivalent<Q::Owned> for Q {
+    fn equivalent(&self, key: &Q::Owned) -> bool {
+        self == key.borrow()
+    }
+
+    fn to_key(&self) -> Q::Owned {
+        self.to_owned()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    // entries in the memo
+    pub size: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} hits, {} misses, {} entries", self.hits, self.misses, self.size)?;
+        if self.evictions > 0 {
+            write!(f, ", {} evicted", self.evictions)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Memo<K, V> {
+    hasher: RandomState,
+    // the entries by the hash of their key, the oldest first
+    buckets: HashMap<u64, Vec<(K, V)>>,
+    // the hashes of the entries from the oldest, with a limit only
+    order: VecDeque<u64>,
+    limit: Option<usize>,
+    len: usize,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            hasher: RandomState::new(),
+            buckets: HashMap::new(),
+            order: VecDeque::new(),
+            limit: None,
+            len: 0,
+            stats: Stats::default(),
+        }
+    }
+
+    // At most limit entries, the oldest ones being evicted first
+    pub fn with_limit(limit: usize) -> Self {
+        assert!(limit > 0, "a memo needs room for at least one entry");
+        Self { limit: Some(limit), ..Self::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats { size: self.len, ..self.stats }
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::default();
+    }
+
+    // Drops the entries, the statistics are kept
+    pub fn clear(&mut self) {
+        self.buckets.clear();
+        self.order.clear();
+        self.len = 0;
+    }
+
+    // Index of the entry in the bucket of the hash
+    fn find(&self, hash: u64, is_key: impl Fn(&K) -> bool) -> Option<usize> {
+        self.buckets.get(&hash)?.iter().position(|(k, _)| is_key(k))
+    }
+
+    // Counted as a hit or a miss
+    pub fn get<Q: Hash + Equivalent<K> + ?Sized>(&mut self, key: &Q) -> Option<&V> {
+        let hash = self.hasher.hash_one(key);
+        match self.find(hash, |k| key.equivalent(k)) {
+            Some(i) => {
+                self.stats.hits += 1;
+                Some(&self.buckets[&hash][i].1)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    // Replaces the value of a key already there
+    pub fn insert(&mut self, key: K, value: V) -> &V {
+        let hash = self.hasher.hash_one(&key);
+        self.insert_hashed(hash, key, value)
+    }
+
+    fn insert_hashed(&mut self, hash: u64, key: K, value: V) -> &V {
+        if let Some(i) = self.find(hash, |k| *k == key) {
+            let entry = &mut self.buckets.get_mut(&hash).expect("entry just found")[i];
+            entry.1 = value;
+            return &entry.1;
+        }
+        if let Some(limit) = self.limit {
+            while self.len >= limit {
+                self.evict_oldest();
+            }
+            self.order.push_back(hash);
+        }
+        self.len += 1;
+        let bucket = self.buckets.entry(hash).or_default();
+        bucket.push((key, value));
+        &bucket[bucket.len() - 1].1
+    }
+
+    fn evict_oldest(&mut self) {
+        let Some(hash) = self.order.pop_front() else {
+            return;
+        };
+        if let Some(bucket) = self.buckets.get_mut(&hash) {
+            bucket.remove(0);
+            if bucket.is_empty() {
+                self.buckets.remove(&hash);
+            }
+            self.len -= 1;
+            self.stats.evictions += 1;
+        }
+    }
+
+    // The value of the key, computed by f the first time only. f gets the
+    // memo for its recursive calls.
+    pub fn call<Q>(&mut self, key: &Q, f: impl FnOnce(&mut Self, &Q) -> V) -> &V
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let hash = self.hasher.hash_one(key);
+        if let Some(i) = self.find(hash, |k| key.equivalent(k)) {
+            self.stats.hits += 1;
+            return &self.buckets[&hash][i].1;
+        }
+        self.stats.misses += 1;
+        let value = f(self, key);
+        self.insert_hashed(hash, key.to_key(), value)
+    }
+
+    // Same without recursion
+    pub fn get_or_insert_with<Q>(&mut self, key: &Q, f: impl FnOnce() -> V) -> &V
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.call(key, |_, _| f())
+    }
+}
+
+impl<K: Hash + Eq, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::Hasher;
+
+    // Keys all sharing the same hash, thus the same bucket
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Colliding(u32);
+
+    impl Hash for Colliding {
+        fn hash<H: Hasher>(&self, _: &mut H) {}
+    }
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        *memo.call(&n, |memo, &n| if n < 2 { n } else { fibonacci(memo, n - 1) + fibonacci(memo, n - 2) })
+    }
+
+    #[test]
+    fn counters() {
+        let mut memo = Memo::<u32, u32>::with_limit(2);
+        assert!(memo.is_empty());
+        assert_eq!(memo.get(&1), None);
+        memo.insert(1, 10);
+        memo.insert(2, 20);
+        assert_eq!(memo.get(&1), Some(&10));
+        assert_eq!(*memo.get_or_insert_with(&3, || 30), 30);
+        assert_eq!(memo.stats(), Stats { hits: 1, misses: 2, evictions: 1, size: 2 });
+        assert_eq!(memo.stats().to_string(), "1 hits, 2 misses, 2 entries, 1 evicted");
+
+        memo.reset_stats();
+        memo.clear();
+        assert_eq!(memo.stats(), Stats::default());
+        assert_eq!(memo.get(&2), None);
+    }
+
+    #[test]
+    fn fifo_eviction_in_a_bucket() {
+        let mut memo = Memo::with_limit(2);
+        memo.insert(Colliding(1), 'a');
+        memo.insert(Colliding(2), 'b');
+        memo.insert(Colliding(3), 'c');
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.get(&Colliding(1)), None);
+        assert_eq!(memo.get(&Colliding(2)), Some(&'b'));
+        assert_eq!(memo.get(&Colliding(3)), Some(&'c'));
+        // the oldest is evicted, not the last one looked up
+        memo.insert(Colliding(4), 'd');
+        assert_eq!(memo.get(&Colliding(2)), None);
+        assert_eq!(memo.get(&Colliding(3)), Some(&'c'));
+        assert_eq!(memo.stats().evictions, 2);
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut memo = Memo::with_limit(2);
+        memo.insert(Colliding(1), 'a');
+        memo.insert(Colliding(2), 'b');
+        assert_eq!(*memo.insert(Colliding(1), 'z'), 'z');
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.stats().evictions, 0);
+        assert_eq!(memo.get(&Colliding(1)), Some(&'z'));
+    }
+
+    #[test]
+    fn borrowed_lookup() {
+        let mut memo = Memo::<String, usize>::new();
+        memo.insert("abc".to_string(), 3);
+        assert_eq!(memo.get("abc"), Some(&3));
+        assert_eq!(*memo.call("abcd", |_, s| s.len()), 4);
+        assert_eq!(memo.get(&"abcd".to_string()), Some(&4));
+        assert_eq!(memo.stats(), Stats { hits: 2, misses: 1, evictions: 0, size: 2 });
+    }
+
+    #[test]
+    fn recursive_call() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 50), 12_586_269_025);
+        // each of 0..=50 computed once, fib(n - 2) found for n from 3
+        assert_eq!(memo.stats(), Stats { hits: 48, misses: 51, evictions: 0, size: 51 });
+        assert_eq!(fibonacci(&mut memo, 50), 12_586_269_025);
+        assert_eq!(memo.stats().hits, 49);
+    }
+}
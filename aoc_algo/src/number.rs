@@ -0,0 +1,229 @@
+/*
+Number theory for the puzzles where several cycles must align:
+  gcd, lcm, gcd_all, lcm_all
+  ext_gcd(a, b)          (g, x, y) with a*x + b*y = g
+  crt(&[(r, m)])         n = r mod m for all the pairs, the moduli need
+                         not be coprime
+  Progression            the steps start + k * period where a cycle
+                         is at a given state, and their intersections
+  first_common(groups)   the first step in one progression of each group
+The values are i64, the intermediate products are computed on i128 and
+the results overflowing i64 are None.
+ */
+
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// 0 when one of the values is 0, None on overflow
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b).map(i64::abs)
+}
+
+pub fn gcd_all(values: impl IntoIterator<Item = i64>) -> i64 {
+    values.into_iter().fold(0, gcd)
+}
+
+// 1 for no values
+pub fn lcm_all(values: impl IntoIterator<Item = i64>) -> Option<i64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+// Extended Euclid: (g, x, y) with g = gcd(a, b) >= 0 and a*x + b*y = g
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1i128, 0i128);
+    let (mut y0, mut y1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (r0, x0, y0) = (-r0, -x0, -y0);
+    }
+    (r0 as i64, x0 as i64, y0 as i64)
+}
+
+// x with a*x = 1 mod m, None when a and m are not coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = ext_gcd(a, m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+// Chinese remainders: the n with n = r mod m for all the (r, m) pairs,
+// as (n mod M, M) with M the lcm of the moduli, which must be > 0.
+// None when the congruences contradict each other.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| crt2(r1, m1, r2, m2))
+}
+
+fn crt2(r1: i64, m1: i64, r2: i64, m2: i64) -> Option<(i64, i64)> {
+    assert!(m1 > 0 && m2 > 0, "the moduli must be positive");
+    let (g, p, _) = ext_gcd(m1, m2);
+    let diff = r2 as i128 - r1 as i128;
+    if diff % g as i128 != 0 {
+        return None;
+    }
+    // n = r1 + m1 * k with m1 * k = diff mod m2, that is k = diff/g * p mod m2/g
+    let m = m1 as i128 / g as i128 * m2 as i128;
+    let k = (diff / g as i128 * p as i128).rem_euclid(m2 as i128 / g as i128);
+    let n = (r1 as i128 + m1 as i128 * k).rem_euclid(m);
+    Some((i64::try_from(n).ok()?, i64::try_from(m).ok()?))
+}
+
+// The steps start, start + period, start + 2 * period...
+// A period of 0 is the start step alone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Progression {
+    pub start: i64,
+    pub period: i64,
+}
+
+impl Progression {
+    pub fn new(start: i64, period: i64) -> Self {
+        assert!(period >= 0, "negative period {period}");
+        Self { start, period }
+    }
+
+    // A single step
+    pub fn once(step: i64) -> Self {
+        Self::new(step, 0)
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        match self.period {
+            0 => n == self.start,
+            p => n >= self.start && (n - self.start) % p == 0,
+        }
+    }
+
+    // The first step not before n, None after a single step
+    pub fn first_from(&self, n: i64) -> Option<i64> {
+        if n <= self.start {
+            return Some(self.start);
+        }
+        match self.period {
+            0 => None,
+            p => self.start.checked_add((n - self.start + p - 1) / p * p),
+        }
+    }
+
+    // The steps in both progressions
+    pub fn intersect(&self, other: &Progression) -> Option<Progression> {
+        if self.period == 0 {
+            return other.contains(self.start).then_some(*self);
+        }
+        if other.period == 0 {
+            return self.contains(other.start).then_some(*other);
+        }
+        let (r, m) = crt(&[(self.start, self.period), (other.start, other.period)])?;
+        // the first common step after both starts
+        let start = Progression::new(r, m).first_from(self.start.max(other.start))?;
+        Some(Progression::new(start, m))
+    }
+}
+
+// The first step in one of the progressions of each group, such as the
+// first step where several cycles are all at one of their goal states.
+// None if there is no such step, or no groups.
+pub fn first_common(groups: &[Vec<Progression>]) -> Option<i64> {
+    let (first, rest) = groups.split_first()?;
+    let mut common = first.clone();
+    for group in rest {
+        common = common.iter().flat_map(|c| group.iter().filter_map(move |g| c.intersect(g))).collect();
+        common.sort_unstable_by_key(|p| (p.start, p.period));
+        common.dedup();
+    }
+    common.iter().map(|p| p.start).min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(i64::MAX, 2), None);
+        assert_eq!(lcm_all([3, 5, 7]), Some(105));
+        assert_eq!(lcm_all([]), Some(1));
+        let (g, x, y) = ext_gcd(240, 46);
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(4, 6), None);
+    }
+
+    #[test]
+    fn crt_coprime_moduli() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 4)]), Some((3, 4)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_non_coprime_moduli() {
+        // 6 and 4 share a 2: n = 2 mod 6 and n = 0 mod 4 is 8 mod 12
+        assert_eq!(crt(&[(2, 6), (0, 4)]), Some((8, 12)));
+        assert_eq!(crt(&[(3, 6), (1, 4), (5, 10)]), Some((45, 60)));
+        // an even and an odd number at once
+        assert_eq!(crt(&[(1, 6), (0, 4)]), None);
+        assert_eq!(crt(&[(4, 8), (4, 8)]), Some((4, 8)));
+    }
+
+    #[test]
+    fn crt_large_moduli() {
+        let m = 1_000_000_007;
+        // the products overflow i64 inside, not the result
+        assert_eq!(crt(&[(1, m), (2, m + 2)]), Some((500_000_007_500_000_029, m * (m + 2))));
+        // the result overflows
+        assert_eq!(crt(&[(1, m), (2, m + 2), (3, m + 4)]), None);
+    }
+
+    #[test]
+    fn progressions() {
+        let p = Progression::new(5, 3);
+        assert!(p.contains(11) && !p.contains(2) && !p.contains(12));
+        assert_eq!(p.first_from(0), Some(5));
+        assert_eq!(p.first_from(12), Some(14));
+        assert_eq!(Progression::once(4).first_from(5), None);
+        assert_eq!(p.intersect(&Progression::new(1, 4)), Some(Progression::new(5, 12)));
+        assert_eq!(p.intersect(&Progression::new(0, 6)), None);
+        assert_eq!(p.intersect(&Progression::once(8)), Some(Progression::once(8)));
+        assert_eq!(p.intersect(&Progression::once(7)), None);
+    }
+
+    #[test]
+    fn first_common_step() {
+        let groups = vec![
+            vec![Progression::new(2, 4), Progression::new(3, 4)],
+            vec![Progression::new(0, 6)],
+        ];
+        assert_eq!(first_common(&groups), Some(6));
+        // after both starts, not the smallest solution of the congruences
+        let groups = vec![vec![Progression::new(10, 3)], vec![Progression::new(1, 5)]];
+        assert_eq!(first_common(&groups), Some(16));
+    }
+
+    #[test]
+    fn first_common_without_solution() {
+        // always even against always odd
+        let groups = vec![vec![Progression::new(0, 2)], vec![Progression::new(1, 4), Progression::new(3, 4)]];
+        assert_eq!(first_common(&groups), None);
+        let groups = vec![vec![Progression::once(3)], vec![Progression::new(4, 1)]];
+        assert_eq!(first_common(&groups), None);
+        assert_eq!(first_common(&[]), None);
+        assert_eq!(first_common(&[vec![Progression::new(1, 2)], vec![]]), None);
+    }
+}
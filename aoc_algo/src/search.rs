@@ -0,0 +1,372 @@
+/*
+Shortest paths over any search state: a tile, a tile with the direction
+and the count of straight moves of a crucible, a set of open valves...
+The solver only gives the start states and the successors of a state:
+  dijkstra(starts, |s| moves as (state, cost), |s| is the goal)
+  astar(starts, successors, |s| estimate of the cost to the goal, is_goal)
+  bfs(starts, |s| next states, is_goal)       when all the moves cost 1
+The path found is returned with all its states, from a start to the goal.
+The _all() variants explore every reachable state instead, for the
+puzzles asking about the whole area or several goals.
+ */
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Cost of the moves, an integer in practice. The default value is the
+// cost of staying at the start.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    // from the start to the goal, both included
+    pub states: Vec<S>,
+    // number of states expanded by the search, for the logs
+    pub expanded: usize,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn goal(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+}
+
+// The states reached by a search, with their lowest cost and the move
+// reaching them at that cost
+pub struct Reached<S, C> {
+    index: HashMap<S, usize>,
+    nodes: Vec<Node<S, C>>,
+    expanded: usize,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Reached<S, C> {
+    fn new() -> Self {
+        Self { index: HashMap::new(), nodes: Vec::new(), expanded: 0 }
+    }
+
+    // Records a way to reach `state`. Returns its node when it is new
+    // or cheaper than known, None when it changes nothing.
+    fn relax(&mut self, state: &S, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(state.clone()) {
+            Entry::Occupied(e) => {
+                let i = *e.get();
+                if cost >= self.nodes[i].cost {
+                    return None;
+                }
+                self.nodes[i].cost = cost;
+                self.nodes[i].parent = parent;
+                Some(i)
+            }
+            Entry::Vacant(e) => {
+                e.insert(self.nodes.len());
+                self.nodes.push(Node { state: state.clone(), cost, parent });
+                Some(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    fn path_from(&self, mut i: usize) -> Vec<S> {
+        let mut states = vec![self.nodes[i].state.clone()];
+        while let Some(p) = self.nodes[i].parent {
+            states.push(self.nodes[p].state.clone());
+            i = p;
+        }
+        states.reverse();
+        states
+    }
+
+    fn found(&self, goal: usize) -> Path<S, C> {
+        Path { cost: self.nodes[goal].cost, states: self.path_from(goal), expanded: self.expanded }
+    }
+
+    // The lowest cost to reach a state, None if not reached
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.index.get(state).map(|&i| self.nodes[i].cost)
+    }
+
+    // A path of lowest cost from a start to the state
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.index.get(state).map(|&i| self.path_from(i))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    // The states with their cost, in the order they were first reached
+    pub fn iter(&self) -> impl Iterator<Item = (&S, C)> + '_ {
+        self.nodes.iter().map(|n| (&n.state, n.cost))
+    }
+}
+
+// Best-first search on the cost plus the heuristic, stopping at the first
+// goal taken out of the queue. A zero heuristic makes it Dijkstra.
+fn best_first<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Reached<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut reached = Reached::new();
+    // (estimated total, cost so far, node): an entry is stale when the
+    // node was reached again at a lower cost after it was queued
+    let mut queue = BinaryHeap::new();
+    for s in starts {
+        if let Some(i) = reached.relax(&s, C::default(), None) {
+            queue.push(Reverse((heuristic(&s), C::default(), i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        if cost > reached.nodes[i].cost {
+            continue;
+        }
+        if is_goal(&reached.nodes[i].state) {
+            return (reached, Some(i));
+        }
+        reached.expanded += 1;
+        let state = reached.nodes[i].state.clone();
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if let Some(n) = reached.relax(&next, next_cost, Some(i)) {
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, n)));
+            }
+        }
+    }
+    (reached, None)
+}
+
+// Path of lowest cost from one of the starts to a goal.
+// The costs of the moves must not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (reached, goal) = best_first(starts, successors, |_| C::default(), is_goal);
+    goal.map(|g| reached.found(g))
+}
+
+// Same, guided by a heuristic which must never overestimate the cost
+// left to a goal (the Manhattan distance for moves of at least 1 on a map)
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (reached, goal) = best_first(starts, successors, heuristic, is_goal);
+    goal.map(|g| reached.found(g))
+}
+
+// The lowest cost of all the states reachable from the starts
+pub fn dijkstra_all<S, C, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I) -> Reached<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(starts, successors, |_| C::default(), |_| false).0
+}
+
+// Breadth-first search, stopping at the first goal reached. The cost
+// of a path is its number of moves.
+fn breadth_first<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Reached<S, usize>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached::new();
+    let mut queue = VecDeque::new();
+    for s in starts {
+        if let Some(i) = reached.relax(&s, 0, None) {
+            queue.push_back(i);
+        }
+    }
+
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&reached.nodes[i].state) {
+            return (reached, Some(i));
+        }
+        reached.expanded += 1;
+        let (state, cost) = (reached.nodes[i].state.clone(), reached.nodes[i].cost);
+        for next in successors(&state) {
+            // the first time a state is reached is with the fewest moves
+            if !reached.index.contains_key(&next) {
+                let n = reached.relax(&next, cost + 1, Some(i)).expect("new state");
+                queue.push_back(n);
+            }
+        }
+    }
+    (reached, None)
+}
+
+// Path with the fewest moves from one of the starts to a goal
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (reached, goal) = breadth_first(starts, successors, is_goal);
+    goal.map(|g| reached.found(g))
+}
+
+// The number of moves to all the states reachable from the starts
+pub fn bfs_all<S, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I) -> Reached<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(starts, successors, |_| false).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &[&str] = &[
+        "S..#....",
+        ".#.#.##.",
+        ".#...#..",
+        ".####.#.",
+        "......#G",
+    ];
+
+    type Tile = (usize, usize);
+
+    fn find(c: u8) -> Tile {
+        (0..GRID.len())
+            .flat_map(|y| (0..GRID[y].len()).map(move |x| (x, y)))
+            .find(|&(x, y)| GRID[y].as_bytes()[x] == c)
+            .unwrap()
+    }
+
+    fn neighbours(&(x, y): &Tile) -> Vec<Tile> {
+        let mut next = Vec::new();
+        if x > 0 { next.push((x - 1, y)); }
+        if y > 0 { next.push((x, y - 1)); }
+        if x + 1 < GRID[y].len() { next.push((x + 1, y)); }
+        if y + 1 < GRID.len() { next.push((x, y + 1)); }
+        next.retain(|&(x, y)| GRID[y].as_bytes()[x] != b'#');
+        next
+    }
+
+    // a move costs 1 to 3 depending on the column it enters
+    fn weighted(t: &Tile) -> Vec<(Tile, u32)> {
+        neighbours(t).into_iter().map(|n| (n, n.0 as u32 % 3 + 1)).collect()
+    }
+
+    fn is_path(states: &[Tile]) -> bool {
+        states.windows(2).all(|w| neighbours(&w[0]).contains(&w[1]))
+    }
+
+    #[test]
+    fn unit_costs_agree_with_bfs() {
+        let (start, goal) = (find(b'S'), find(b'G'));
+        let b = bfs([start], neighbours, |&t| t == goal).unwrap();
+        let d = dijkstra([start], |t| neighbours(t).into_iter().map(|n| (n, 1)), |&t| t == goal).unwrap();
+        let manhattan = |&(x, y): &Tile| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+        let a = astar([start], |t| neighbours(t).into_iter().map(|n| (n, 1)), manhattan, |&t| t == goal).unwrap();
+        assert_eq!(b.cost, 15);
+        assert_eq!(d.cost, b.cost);
+        assert_eq!(a.cost, b.cost);
+        for p in [&b, &d, &a] {
+            assert_eq!((*p.start(), *p.goal()), (start, goal));
+            assert_eq!(p.states.len(), p.cost + 1);
+            assert!(is_path(&p.states));
+        }
+        // the heuristic only saves work
+        assert!(a.expanded <= d.expanded);
+    }
+
+    #[test]
+    fn all_reached_agree_with_bfs() {
+        let start = find(b'S');
+        let b = bfs_all([start], neighbours);
+        let d = dijkstra_all([start], |t| neighbours(t).into_iter().map(|n| (n, 1)));
+        assert_eq!(b.len(), d.len());
+        for (t, cost) in b.iter() {
+            assert_eq!(d.cost(t), Some(cost));
+            assert_eq!(b.path_to(t).unwrap().len(), cost + 1);
+        }
+        assert_eq!(b.cost(&(3, 0)), None);
+    }
+
+    #[test]
+    fn weighted_costs_are_lowest() {
+        let (start, goal) = (find(b'S'), find(b'G'));
+        let d = dijkstra([start], weighted, |&t| t == goal).unwrap();
+        // the cheapest move is 1, the Manhattan distance never overestimates
+        let manhattan = |&(x, y): &Tile| (goal.0.abs_diff(x) + goal.1.abs_diff(y)) as u32;
+        let a = astar([start], weighted, manhattan, |&t| t == goal).unwrap();
+        let all = dijkstra_all([start], weighted);
+        assert_eq!(a.cost, d.cost);
+        assert_eq!(all.cost(&goal), Some(d.cost));
+        let along: u32 = d.states.windows(2).map(|w| w[1].0 as u32 % 3 + 1).sum();
+        assert_eq!(along, d.cost);
+        // no path is cheaper than the fewest moves
+        assert!(d.cost as usize >= bfs([start], neighbours, |&t| t == goal).unwrap().cost);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        // a wall
+        let start = find(b'S');
+        assert!(bfs([start], neighbours, |&t| t == (3, 0)).is_none());
+        assert!(dijkstra([start], weighted, |&t| t == (3, 0)).is_none());
+    }
+
+    #[test]
+    fn several_starts() {
+        let (start, goal) = (find(b'S'), find(b'G'));
+        let p = bfs([start, (7, 2)], neighbours, |&t| t == goal).unwrap();
+        assert_eq!((p.cost, *p.start()), (2, (7, 2)));
+    }
+}
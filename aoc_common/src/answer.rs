@@ -0,0 +1,80 @@
+/*
+Answer of a puzzle, as returned by the solvers: most answers are
+numbers, a few are a text (a code, a word...).
+ */
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl Answer {
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Answer::Int(n) => Some(*n),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => n.fmt(f),
+            Answer::Text(s) => f.pad(s),
+        }
+    }
+}
+
+// An expected output, typed as the solver would return it:
+// a number when the whole text is one.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(i64::from_str(s).map_or_else(|_| Answer::Text(s.to_string()), Answer::Int))
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty)*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n.into())
+            }
+        }
+    )*};
+}
+
+from_int!(i8 i16 i32 i64 u8 u16 u32);
+
+// Values out of the i64 range are only kept as their decimal text
+macro_rules! from_wide_int {
+    ($($t:ty)*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+            }
+        }
+    )*};
+}
+
+from_wide_int!(u64 usize i128 u128 isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
@@ -0,0 +1,14 @@
+/*
+Code shared by all the puzzles of the workspace.
+ */
+
+pub mod answer;
+pub mod log;
+pub mod params;
+pub mod parse;
+pub mod solver;
+
+pub use answer::Answer;
+pub use params::{main_with_params, Param, Params};
+pub use parse::ParseError;
+pub use solver::{last_timings, lines, main, run, Solver, Timings};
@@ -0,0 +1,199 @@
+/*
+Leveled diagnostics of the solvers on stderr, instead of dumps printed
+on every run. Only the warnings and errors are shown by default:
+  -v, -vv, -vvv              also the info, debug, trace messages
+  -q                         only the errors
+  AOC_LOG=debug              the default level, from the environment
+  AOC_LOG=day_21_a=trace     the level of the modules under a module path
+  AOC_LOG=info,day_17::part_b=trace,day_22_a=off
+The -v and -q flags change the default level, not the module ones.
+ */
+
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{PoisonError, RwLock};
+
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+const LEVELS: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        })
+    }
+}
+
+// A level or "off", as the highest level shown: 0 shows nothing
+fn parse_filter(s: &str) -> Result<usize, String> {
+    let s = s.trim().to_ascii_lowercase();
+    if s == "off" {
+        return Ok(0);
+    }
+    LEVELS
+        .iter()
+        .find(|l| l.to_string().to_ascii_lowercase() == s)
+        .map(|&l| l as usize)
+        .ok_or_else(|| format!("invalid log level '{s}', expected off, error, warn, info, debug or trace"))
+}
+
+struct Filter {
+    default: usize,
+    // module path prefixes, the most specific one applies
+    modules: Vec<(String, usize)>,
+}
+
+impl Filter {
+    fn max(&self) -> usize {
+        self.modules.iter().map(|&(_, l)| l).fold(self.default, usize::max)
+    }
+
+    fn level_of(&self, module: &str) -> usize {
+        self.modules
+            .iter()
+            .filter(|(m, _)| {
+                module.strip_prefix(m.as_str()).is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(m, _)| m.len())
+            .map_or(self.default, |&(_, l)| l)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter { default: Level::Warn as usize, modules: Vec::new() });
+// Highest level of the filter, to skip the disabled messages without locking
+static MAX_LEVEL: AtomicUsize = AtomicUsize::new(Level::Warn as usize);
+
+fn update(f: impl FnOnce(&mut Filter)) {
+    let mut filter = FILTER.write().unwrap_or_else(PoisonError::into_inner);
+    f(&mut filter);
+    MAX_LEVEL.store(filter.max(), Ordering::Relaxed);
+}
+
+// Replaces the whole filter by a AOC_LOG specification:
+// comma-separated "level" for the default and "module=level" items.
+pub fn configure(spec: &str) -> Result<(), String> {
+    let mut default = Level::Warn as usize;
+    let mut modules = Vec::new();
+    for item in spec.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+        match item.split_once('=') {
+            // the directory names are accepted too: "day_22-B" is the day_22_b crate
+            Some((m, l)) => modules.push((m.trim().replace('-', "_").to_ascii_lowercase(), parse_filter(l)?)),
+            None => default = parse_filter(item)?,
+        }
+    }
+    update(|f| *f = Filter { default, modules });
+    Ok(())
+}
+
+// Default level of the modules without their own, None for off
+pub fn set_level(level: Option<Level>) {
+    update(|f| f.default = level.map_or(0, |l| l as usize));
+}
+
+// Shows the messages of a module up to level at least, for a report
+// asked by a switch of the solver
+pub fn show_module(module: &str, level: Level) {
+    update(|f| {
+        if f.level_of(module) < level as usize {
+            f.modules.push((module.to_string(), level as usize));
+        }
+    });
+}
+
+pub fn enabled(level: Level, module: &str) -> bool {
+    let level = level as usize;
+    level <= MAX_LEVEL.load(Ordering::Relaxed)
+        && level <= FILTER.read().unwrap_or_else(PoisonError::into_inner).level_of(module)
+}
+
+// Output of the macros, already filtered
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    eprintln!("[{level:<5} {module}] {args}");
+}
+
+// Configures the filter from AOC_LOG and the -v/-q flags of the command
+// line, and returns the other arguments.
+pub fn init(args: Vec<String>) -> Result<Vec<String>, String> {
+    if let Ok(spec) = env::var(LOG_ENV) {
+        configure(&spec).map_err(|e| format!("{LOG_ENV}: {e}"))?;
+    }
+
+    let mut verbosity: Option<i32> = None;
+    let mut rest = Vec::new();
+    for a in args {
+        let v = match a.as_str() {
+            "-q" | "--quiet" => -1,
+            "--verbose" => 1,
+            _ if a.len() > 1 && a.starts_with('-') && a[1..].bytes().all(|b| b == b'v') => a.len() as i32 - 1,
+            _ => {
+                rest.push(a);
+                continue;
+            }
+        };
+        *verbosity.get_or_insert(0) += v;
+    }
+
+    if let Some(v) = verbosity {
+        let level = (Level::Warn as i32 + v).clamp(Level::Error as i32, Level::Trace as i32);
+        set_level(Some(LEVELS[level as usize - 1]));
+    }
+    Ok(rest)
+}
+
+// Message at the given level, when enabled for the calling module
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+// Guard for the diagnostics too costly to build when not shown
+#[macro_export]
+macro_rules! log_enabled {
+    ($level:expr) => {
+        $crate::log::enabled($level, module_path!())
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
@@ -0,0 +1,173 @@
+/*
+Runtime parameters of the solvers, instead of constants to edit between
+the sample and the actual puzzle input:
+  --part 2              the part to solve, for the solvers of both parts
+  --param steps=64      a value for one of the parameters the solver declares
+  --explain             same as --param explain=1, for the 0..=1 switches
+ */
+
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+use std::str::FromStr;
+
+use crate::answer::Answer;
+use crate::log;
+
+// Declaration of a parameter accepted by a solver.
+// The solver chooses the value used when it is not given.
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Param {
+    // 0 or 1, also given as --<name>
+    pub fn is_switch(&self) -> bool {
+        (self.min, self.max) == (0, 1)
+    }
+}
+
+// The values given for the parameters declared by a solver
+#[derive(Clone, Debug)]
+pub struct Params {
+    decl: &'static [Param],
+    part: u32,
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+    // Part 1, with all the parameters unset
+    pub fn new(decl: &'static [Param]) -> Self {
+        Self { decl, part: 1, values: Vec::new() }
+    }
+
+    // Part 2, with all the parameters unset
+    pub fn new_part_2(decl: &'static [Param]) -> Self {
+        Self { decl, part: 2, values: Vec::new() }
+    }
+
+    pub fn declared(&self) -> &'static [Param] {
+        self.decl
+    }
+
+    pub fn set_part(&mut self, part: &str) -> Result<(), String> {
+        match part {
+            "1" | "A" | "a" => self.part = 1,
+            "2" | "B" | "b" => self.part = 2,
+            _ => return Err(format!("invalid part '{part}', expected 1 or 2")),
+        }
+        Ok(())
+    }
+
+    // "name=value", for a declared name and a value within its bounds
+    pub fn set(&mut self, arg: &str) -> Result<(), String> {
+        let Some((name, value)) = arg.split_once('=') else {
+            return Err(format!("invalid parameter '{arg}', expected name=value"));
+        };
+        let Some(p) = self.decl.iter().find(|p| p.name == name.trim()) else {
+            return Err(format!("unknown parameter '{}'", name.trim()));
+        };
+        let Ok(v) = i64::from_str(value.trim()) else {
+            return Err(format!("invalid value '{}' for {}, expected an integer", value.trim(), p.name));
+        };
+        if v < p.min || v > p.max {
+            return Err(format!("{} must be within {}..={}, got {v}", p.name, p.min, p.max));
+        }
+
+        self.values.retain(|(n, _)| *n != p.name);
+        self.values.push((p.name, v));
+        Ok(())
+    }
+
+    // "--name" for a declared switch, which is set to 1
+    pub fn set_switch(&mut self, arg: &str) -> Result<(), String> {
+        let name = arg.strip_prefix("--").unwrap_or_default();
+        match self.decl.iter().find(|p| p.name == name && p.is_switch()) {
+            Some(p) => self.set(&format!("{}=1", p.name)),
+            None => Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    // Parses the "--part N", "--param name=value" and "--switch" arguments
+    pub fn from_args(decl: &'static [Param], args: &[String]) -> Result<Self, String> {
+        let mut params = Self::new(decl);
+        params.set_args(args)?;
+        Ok(params)
+    }
+
+    // Same over the values already set
+    pub fn set_args(&mut self, args: &[String]) -> Result<(), String> {
+        let mut it = args.iter();
+        while let Some(a) = it.next() {
+            match a.as_str() {
+                "--part" | "--param" => {
+                    let Some(v) = it.next() else {
+                        return Err(format!("{a} expects a value"));
+                    };
+                    if a == "--part" {
+                        self.set_part(v)?;
+                    } else {
+                        self.set(v)?;
+                    }
+                }
+                _ => self.set_switch(a)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn part_2(&self) -> bool {
+        self.part == 2
+    }
+
+    // The value given for a parameter, None when not set
+    pub fn get(&self, name: &str) -> Option<i64> {
+        assert!(
+            self.decl.iter().any(|p| p.name == name),
+            "parameter {name} is not declared by the solver"
+        );
+        self.values.iter().find(|(n, _)| *n == name).map(|&(_, v)| v)
+    }
+
+    // true when a switch is set to 1
+    pub fn switch(&self, name: &str) -> bool {
+        self.get(name) == Some(1)
+    }
+}
+
+// One line per declared parameter, for the usage messages
+pub fn describe(decl: &[Param]) -> String {
+    decl.iter()
+        .map(|p| {
+            if p.is_switch() {
+                return format!("  --{}  {}\n", p.name, p.help);
+            }
+            let range = match (p.min, p.max) {
+                (i64::MIN, i64::MAX) => "integer".to_string(),
+                (min, i64::MAX) => format!("{min}.."),
+                (min, max) => format!("{min}..={max}"),
+            };
+            format!("  --param {}=<{range}>  {}\n", p.name, p.help)
+        })
+        .collect()
+}
+
+// main() of the puzzle binaries taking runtime parameters on the command line
+pub fn main_with_params<F>(decl: &'static [Param], run: F)
+where
+    F: FnOnce(&mut dyn BufRead, &Params) -> io::Result<Answer>,
+{
+    let params = match log::init(env::args().skip(1).collect()).and_then(|args| Params::from_args(decl, &args)) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("{e}");
+            eprint!("Usage: [-v|-q] [--part 1|2] [--param name=value] [--switch]...\n{}", describe(decl));
+            process::exit(1);
+        }
+    };
+    crate::solver::solve_stdin(|r| run(r, &params));
+}
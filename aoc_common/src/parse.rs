@@ -0,0 +1,333 @@
+/*
+Errors of the puzzle input parsers, located in the input so that a
+malformed or hand-edited input gives a readable diagnostic:
+  input error: line 3, column 12: expected a number, found 'x5'
+
+The helpers for the usual formats take the whole `line` and the `part`
+of it to parse, a slice of line, so that their errors give the column
+in the line. They ignore the extra spaces, like the aligned columns of
+some samples:
+  record::<u32>(l, "Game")         "Game 12: rest"          (12, "rest")
+  pair(l, part, "->", expected)    "a -> b, c"              ("a", "b, c")
+  key_values(l, "->")              "a -> b, c"              ("a", ["b", "c"])
+  numbers::<i64>(l, part)          "1 -2   3" or "1,-2, 3"  [1, -2, 3]
+  numbers_array::<i64, 3>(l, part) exactly 3 numbers        [1, -2, 3]
+  blocks(lines)                    the paragraphs separated by empty lines
+  grid(lines, expected, tile)      a map of one character per tile
+ */
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    // 1-based, 0 when not known yet: the line parsers only know the
+    // column, the line number is added by the caller reading the input.
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    // the offending token, empty at the end of the line
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self { line: 0, column, expected: expected.into(), found: found.into() }
+    }
+
+    // Error on `token`, a slice of the parsed `line`
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(column_of(line, token), expected, token)
+    }
+
+    // Error on a missing token after the end of `line`
+    pub fn at_end(line: &str, expected: impl Into<String>) -> Self {
+        Self::new(line.chars().count() + 1, expected, "")
+    }
+
+    // Relocates an error of a parser given only the `part` slice of `line`
+    pub fn within(mut self, line: &str, part: &str) -> Self {
+        self.column += column_of(line, part) - 1;
+        self
+    }
+
+    // Sets the line number, unless already known
+    pub fn with_line(mut self, line: usize) -> Self {
+        if self.line == 0 {
+            self.line = line;
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, ", self.line)?;
+        }
+        write!(f, "column {}: expected {}, ", self.column, self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found '{}'", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// The solvers report their errors as io::Error, like the reading ones
+impl From<ParseError> for io::Error {
+    fn from(e: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+// 1-based column of `token` in `line`, in characters.
+// `token` must be a slice of `line`, else the first column is returned.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    if offset > line.len() || !line.is_char_boundary(offset) {
+        return 1;
+    }
+    line[..offset].chars().count() + 1
+}
+
+// Parses `token`, a slice of `line`, as a number
+pub fn number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    T::from_str(token).map_err(|_| ParseError::at(line, token, "a number"))
+}
+
+// The next token of a split of `line`, which must be present
+pub fn next<'a>(
+    line: &str,
+    tokens: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    tokens.next().ok_or_else(|| ParseError::at_end(line, expected))
+}
+
+// The items of `part` separated by whitespace and/or commas
+pub fn items(part: &str) -> impl Iterator<Item = &str> {
+    part.split(|c: char| c == ',' || c.is_whitespace()).filter(|t| !t.is_empty())
+}
+
+// The two sides of `part`, a slice of `line`, around the first `sep`
+// after its leading spaces, trimmed. `expected` describes the whole
+// part for the error.
+pub fn pair<'a>(line: &str, part: &'a str, sep: &str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let part = part.trim();
+    match part.split_once(sep) {
+        Some((a, b)) => Ok((a.trim(), b.trim())),
+        None => Err(ParseError::at(line, part, expected)),
+    }
+}
+
+// "<key> <sep> <values>" with the values separated by whitespace and/or
+// commas, like "broadcaster -> a, b, c" or "Time:   7  15   30"
+pub fn key_values<'a>(line: &'a str, sep: &str) -> Result<(&'a str, Vec<&'a str>), ParseError> {
+    let (key, values) = pair(line, line, sep, &format!("<key> {sep} <values>"))?;
+    if key.is_empty() {
+        return Err(ParseError::new(1, "a key", line));
+    }
+    Ok((key, items(values).collect()))
+}
+
+// A labelled record "<label> <n>: <rest>", returns n and the trimmed rest
+pub fn record<'a, T: FromStr>(line: &'a str, label: &str) -> Result<(T, &'a str), ParseError> {
+    let (head, rest) = pair(line, line, ":", &format!("{label} <n>: ..."))?;
+    let Some(n) = head.strip_prefix(label) else {
+        return Err(ParseError::at(line, head, format!("{label} <n>")));
+    };
+    let n = n.trim_start();
+    if n.is_empty() {
+        return Err(ParseError::at(line, &head[head.len()..], format!("the number of the {label}")));
+    }
+    Ok((number(line, n)?, rest))
+}
+
+// The numbers of `part`, a slice of `line`, separated by whitespace
+// and/or commas, negative ones included
+pub fn numbers<T: FromStr>(line: &str, part: &str) -> Result<Vec<T>, ParseError> {
+    items(part).map(|t| number(line, t)).collect()
+}
+
+// Exactly N numbers, like the coordinates of a point
+pub fn numbers_array<T: FromStr, const N: usize>(line: &str, part: &str) -> Result<[T; N], ParseError> {
+    let mut tokens = items(part);
+    let mut values = Vec::with_capacity(N);
+    for _ in 0..N {
+        let Some(t) = tokens.next() else {
+            return Err(ParseError::at_end(part, format!("{N} numbers")).within(line, part));
+        };
+        values.push(number(line, t)?);
+    }
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::at(line, extra, format!("only {N} numbers")));
+    }
+    Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+// A paragraph of the input: lines up to an empty one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block {
+    // 1-based number of its first line in the input
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Block {
+    // The lines of the block as a map, see grid(), with the errors
+    // located in the whole input
+    pub fn grid<T>(&self, expected: &str, tile: impl FnMut(char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+        grid(&self.lines, expected, tile).map_err(|mut e| {
+            e.line += self.line - 1;
+            e
+        })
+    }
+}
+
+// The paragraphs of the input, separated by one or more empty lines
+// (or lines of spaces). The spaces at the end of the lines are dropped.
+pub fn blocks<I, S>(lines: I) -> Vec<Block>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+    for (n, l) in lines.into_iter().enumerate() {
+        let l = l.as_ref().trim_end();
+        if l.is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current.get_or_insert_with(|| Block { line: n + 1, lines: Vec::new() }).lines.push(l.to_string());
+        }
+    }
+    blocks.extend(current);
+    blocks
+}
+
+// A map of one character per tile, `tile` returning None for the invalid
+// characters, as rows of the same width. The empty lines are ignored.
+pub fn grid<T, I, S>(lines: I, expected: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for (n, l) in lines.into_iter().enumerate() {
+        let l = l.as_ref().trim_end();
+        if l.is_empty() {
+            continue;
+        }
+        let width = rows.first().map(Vec::len);
+        let mut row = Vec::with_capacity(width.unwrap_or(0));
+        for (i, c) in l.chars().enumerate() {
+            if width.is_some_and(|w| i >= w) {
+                return Err(ParseError::new(i + 1, "the end of the row", c).with_line(n + 1));
+            }
+            row.push(tile(c).ok_or_else(|| ParseError::new(i + 1, expected, c).with_line(n + 1))?);
+        }
+        if width.is_some_and(|w| row.len() < w) {
+            return Err(ParseError::at_end(l, expected).with_line(n + 1));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (line, column, found) of an error
+    fn located(e: ParseError) -> (usize, usize, String) {
+        (e.line, e.column, e.found)
+    }
+
+    #[test]
+    fn columns_in_characters() {
+        let l = "é -> ü, x";
+        assert_eq!(column_of(l, &l[0..0]), 1);
+        assert_eq!(column_of(l, &l[3..5]), 3);
+        assert_eq!(column_of(l, &l[l.len()..]), 10);
+        // not a slice of the line
+        assert_eq!(column_of(l, "x"), 1);
+    }
+
+    #[test]
+    fn error_display() {
+        let l = "Game 1: 3 blue";
+        let e = ParseError::at(l, &l[10..14], "a colour").with_line(7);
+        assert_eq!(e.to_string(), "line 7, column 11: expected a colour, found 'blue'");
+        // the first line number given is kept
+        assert_eq!(e.with_line(2).line, 7);
+        let e = ParseError::at_end(l, "a count");
+        assert_eq!(e.to_string(), "column 15: expected a count, found end of line");
+    }
+
+    #[test]
+    fn number_errors() {
+        let l = "seeds: 79 1x4 55";
+        assert_eq!(numbers::<u32>(l, &l[6..]), Err(ParseError::new(11, "a number", "1x4")));
+        assert_eq!(numbers::<i64>(l, &l[6..9]).unwrap(), [79]);
+        let l = "1, -2,3";
+        assert_eq!(numbers::<i64>(l, l).unwrap(), [1, -2, 3]);
+        assert_eq!(located(numbers::<u8>(l, l).unwrap_err()), (0, 4, "-2".to_string()));
+    }
+
+    #[test]
+    fn numbers_array_errors() {
+        let l = "p=1,2 v=3";
+        assert_eq!(numbers_array::<i64, 2>(l, &l[2..5]).unwrap(), [1, 2]);
+        // missing: after the end of the part, not of the line
+        assert_eq!(located(numbers_array::<i64, 3>(l, &l[2..5]).unwrap_err()), (0, 6, String::new()));
+        assert_eq!(located(numbers_array::<i64, 1>(l, &l[2..5]).unwrap_err()), (0, 5, "2".to_string()));
+    }
+
+    #[test]
+    fn record_errors() {
+        let l = "Card  12: 41 48";
+        assert_eq!(record::<u32>(l, "Card").unwrap(), (12, "41 48"));
+        assert_eq!(located(record::<u32>("Card 1x: 2", "Card").unwrap_err()), (0, 6, "1x".to_string()));
+        assert_eq!(located(record::<u32>("Game 3: 2", "Card").unwrap_err()), (0, 1, "Game 3".to_string()));
+        assert_eq!(located(record::<u32>("Card: 2", "Card").unwrap_err()).1, 5);
+        assert_eq!(located(record::<u32>("  Card 3 2", "Card").unwrap_err()), (0, 3, "Card 3 2".to_string()));
+    }
+
+    #[test]
+    fn pair_and_key_values() {
+        let l = "broadcaster -> a, b,c";
+        assert_eq!(key_values(l, "->").unwrap(), ("broadcaster", vec!["a", "b", "c"]));
+        assert_eq!(located(key_values("-> a", "->").unwrap_err()).1, 1);
+        let l = "x = (a b)";
+        assert_eq!(located(pair(l, &l[4..], ",", "<a>, <b>").unwrap_err()), (0, 5, "(a b)".to_string()));
+    }
+
+    #[test]
+    fn grid_errors() {
+        let tile = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        let lines = ["#..", "", ".#.", ".x."];
+        assert_eq!(located(grid(lines, "a tile", tile).unwrap_err()), (4, 2, "x".to_string()));
+        assert_eq!(located(grid(["#..", "#...."], "a tile", tile).unwrap_err()), (2, 4, ".".to_string()));
+        assert_eq!(located(grid(["#..", "#."], "a tile", tile).unwrap_err()), (2, 3, String::new()));
+        assert_eq!(grid(["#.", ".#"], "a tile", tile).unwrap(), [[true, false], [false, true]]);
+    }
+
+    #[test]
+    fn block_errors() {
+        let lines = ["header", "", "", "..", ".?", "", "#"];
+        let blocks = blocks(lines);
+        assert_eq!(blocks.iter().map(|b| b.line).collect::<Vec<_>>(), [1, 4, 7]);
+        let e = blocks[1].grid("a tile", |c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!(located(e), (5, 2, "?".to_string()));
+    }
+}
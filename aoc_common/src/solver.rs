@@ -0,0 +1,105 @@
+/*
+Common structure of all the puzzle solvers, and the driver
+feeding them with the puzzle input.
+ */
+
+use std::cell::Cell;
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::log;
+use crate::parse::ParseError;
+
+// Solver for one particular problem.
+//
+// Most puzzles can be solved while streaming the input one line at a time:
+// they only implement process(). The ones needing to control how the
+// whole input is read (several sections, look-ahead...) override
+// process_all() instead.
+pub trait Solver {
+    // process one text line of input, already trimmed
+    fn process(&mut self, _l: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    // process the whole input, by default one line at a time
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+        for (n, l) in lines(reader).enumerate() {
+            self.process(&l?).map_err(|e| e.with_line(n + 1))?;
+        }
+        Ok(())
+    }
+
+    // called once after all the input has been processed
+    fn postprocess(&mut self) {}
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer;
+}
+
+// Iterates over the lines of the input, with the leading and trailing
+// whitespace removed (including the \n or \r\n)
+pub fn lines(reader: &mut dyn BufRead) -> impl Iterator<Item = io::Result<String>> + '_ {
+    reader.lines().map(|l| l.map(|s| s.trim().to_string()))
+}
+
+// Duration of each phase of a run, for the benchmarks.
+// The whole-input solvers often do all the work while parsing.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration, // process_all()
+    pub solve: Duration, // postprocess() and result()
+}
+
+thread_local! {
+    static LAST_TIMINGS: Cell<Timings> = Cell::new(Timings::default());
+}
+
+// Timings of the last run() done by this thread
+pub fn last_timings() -> Timings {
+    LAST_TIMINGS.get()
+}
+
+// Sends all the input to the solver and returns its result
+pub fn run<S: Solver>(mut s: S, reader: &mut dyn BufRead) -> io::Result<Answer> {
+    let start = Instant::now();
+    s.process_all(reader)?;
+    let parsed = Instant::now();
+    s.postprocess();
+    let result = s.result();
+
+    LAST_TIMINGS.set(Timings {
+        parse: parsed - start,
+        solve: parsed.elapsed(),
+    });
+    Ok(result)
+}
+
+// Common main() of the puzzle binaries: solve the input read from stdin
+pub fn main<F>(run: F)
+where
+    F: FnOnce(&mut dyn BufRead) -> io::Result<Answer>,
+{
+    if let Err(e) = log::init(env::args().skip(1).collect()) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+    solve_stdin(run);
+}
+
+// The binaries taking arguments must initialize the log themselves
+pub(crate) fn solve_stdin<F>(run: F)
+where
+    F: FnOnce(&mut dyn BufRead) -> io::Result<Answer>,
+{
+    match run(&mut io::stdin().lock()) {
+        Ok(result) => println!("{result}"),
+        Err(e) => {
+            eprintln!("input error: {e}");
+            process::exit(1);
+        }
+    }
+}
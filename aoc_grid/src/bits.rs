@@ -0,0 +1,358 @@
+/*
+Bitboards of any width, for the bit-parallel map puzzles: one bit per
+tile, so that comparing, counting or moving a whole row is a few word
+operations instead of a loop on the tiles.
+  BitRow     a row of bits, bit i being the tile at x = i:
+             &a & &b, |, ^, !, << and >> (towards the higher and lower
+             indices, the bits moved out of the width are lost),
+             count_ones, from_range for the masks
+  BitBoard   rows of the same width, with the columns and the transpose
+Any width works, the rows are stored on as many u64 as needed.
+ */
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range, Shl, Shr};
+
+use crate::Grid;
+
+const WORD: usize = u64::BITS as usize;
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitRow {
+    width: usize,
+    // the bits after the width are always 0
+    words: Vec<u64>,
+}
+
+impl BitRow {
+    // All the bits clear
+    pub fn new(width: usize) -> Self {
+        Self { width, words: vec![0; width.div_ceil(WORD)] }
+    }
+
+    // The mask of the bits in the range
+    pub fn from_range(width: usize, r: Range<usize>) -> Self {
+        assert!(r.end <= width, "range {r:?} out of a row of {width} bits");
+        let mut row = Self::new(width);
+        for i in r {
+            row.words[i / WORD] |= 1 << (i % WORD);
+        }
+        row
+    }
+
+    // The bits set at the given indices
+    pub fn from_indices(width: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut row = Self::new(width);
+        for i in indices {
+            row.set(i, true);
+        }
+        row
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    // false outside of the row
+    pub fn get(&self, i: usize) -> bool {
+        i < self.width && self.words[i / WORD] & (1 << (i % WORD)) != 0
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.width, "bit {i} out of a row of {} bits", self.width);
+        if value {
+            self.words[i / WORD] |= 1 << (i % WORD);
+        } else {
+            self.words[i / WORD] &= !(1 << (i % WORD));
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    // true when at least one bit is set
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    // The indices of the bits set, in increasing order
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(k, &w)| {
+            let mut w = w;
+            std::iter::from_fn(move || {
+                (w != 0).then(|| {
+                    let i = w.trailing_zeros() as usize;
+                    w &= w - 1;
+                    k * WORD + i
+                })
+            })
+        })
+    }
+
+    fn clear_tail(&mut self) {
+        if !self.width.is_multiple_of(WORD) {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << (self.width % WORD)) - 1;
+            }
+        }
+    }
+
+    fn zip_with(&mut self, other: &BitRow, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(self.width, other.width, "rows of different widths");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+macro_rules! bit_ops {
+    ($($op:ident $f:ident $op_assign:ident $f_assign:ident $e:tt),*) => {$(
+        impl $op_assign<&BitRow> for BitRow {
+            fn $f_assign(&mut self, other: &BitRow) {
+                self.zip_with(other, |a, b| a $e b);
+            }
+        }
+
+        impl $op<&BitRow> for &BitRow {
+            type Output = BitRow;
+            fn $f(self, other: &BitRow) -> BitRow {
+                let mut row = self.clone();
+                row.$f_assign(other);
+                row
+            }
+        }
+
+        impl $op<&BitRow> for BitRow {
+            type Output = BitRow;
+            fn $f(mut self, other: &BitRow) -> BitRow {
+                self.$f_assign(other);
+                self
+            }
+        }
+    )*};
+}
+
+bit_ops!(BitAnd bitand BitAndAssign bitand_assign &, BitOr bitor BitOrAssign bitor_assign |,
+         BitXor bitxor BitXorAssign bitxor_assign ^);
+
+impl Not for &BitRow {
+    type Output = BitRow;
+    fn not(self) -> BitRow {
+        let mut row = BitRow { width: self.width, words: self.words.iter().map(|w| !w).collect() };
+        row.clear_tail();
+        row
+    }
+}
+
+// Moves the bit i to i + n
+impl Shl<usize> for &BitRow {
+    type Output = BitRow;
+    fn shl(self, n: usize) -> BitRow {
+        let mut row = BitRow::new(self.width);
+        let (skip, bits) = (n / WORD, n % WORD);
+        for k in skip..self.words.len() {
+            let mut w = self.words[k - skip] << bits;
+            if bits > 0 && k > skip {
+                w |= self.words[k - skip - 1] >> (WORD - bits);
+            }
+            row.words[k] = w;
+        }
+        row.clear_tail();
+        row
+    }
+}
+
+// Moves the bit i to i - n
+impl Shr<usize> for &BitRow {
+    type Output = BitRow;
+    fn shr(self, n: usize) -> BitRow {
+        let mut row = BitRow::new(self.width);
+        let (skip, bits) = (n / WORD, n % WORD);
+        for k in 0..self.words.len().saturating_sub(skip) {
+            let mut w = self.words[k + skip] >> bits;
+            if bits > 0 && k + skip + 1 < self.words.len() {
+                w |= self.words[k + skip + 1] << (WORD - bits);
+            }
+            row.words[k] = w;
+        }
+        row
+    }
+}
+
+// '#' for the bits set, from bit 0 on the left like the map
+impl fmt::Debug for BitRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s: String = (0..self.width).map(|i| if self.get(i) { '#' } else { '.' }).collect();
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitBoard {
+    width: usize,
+    rows: Vec<BitRow>,
+}
+
+impl BitBoard {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, rows: vec![BitRow::new(width); height] }
+    }
+
+    pub fn from_rows(rows: Vec<BitRow>) -> Self {
+        let width = rows.first().map_or(0, BitRow::width);
+        assert!(rows.iter().all(|r| r.width() == width), "the rows of a board must have the same width");
+        Self { width, rows }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> bool) -> Self {
+        let rows = (0..height).map(|y| BitRow::from_indices(width, (0..width).filter(|&x| f(x, y)))).collect();
+        Self { width, rows }
+    }
+
+    // The tiles of a grid matching a predicate
+    pub fn from_grid<T>(grid: &Grid<T>, mut f: impl FnMut(&T) -> bool) -> Self {
+        Self::from_fn(grid.width(), grid.height(), |x, y| f(&grid[(x, y)]))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    // false outside of the board
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.rows.get(y).is_some_and(|r| r.get(x))
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        self.rows[y].set(x, value);
+    }
+
+    pub fn row(&self, y: usize) -> &BitRow {
+        &self.rows[y]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut BitRow {
+        &mut self.rows[y]
+    }
+
+    pub fn rows(&self) -> &[BitRow] {
+        &self.rows
+    }
+
+    // The column x as a row of height bits, bit y being the tile at y
+    pub fn column(&self, x: usize) -> BitRow {
+        BitRow::from_indices(self.height(), (0..self.height()).filter(|&y| self.rows[y].get(x)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = BitRow> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // Swaps the rows and the columns
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::new(self.height(), self.width);
+        for (y, row) in self.rows.iter().enumerate() {
+            for x in row.iter_ones() {
+                t.rows[x].set(y, true);
+            }
+        }
+        t
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.rows.iter().map(BitRow::count_ones).sum()
+    }
+}
+
+impl fmt::Debug for BitBoard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{row:?}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A row across a word boundary
+    fn row() -> BitRow {
+        BitRow::from_indices(70, [0, 3, 63, 64, 69])
+    }
+
+    #[test]
+    fn bits_of_a_row() {
+        let r = row();
+        assert_eq!(r.iter_ones().collect::<Vec<_>>(), [0, 3, 63, 64, 69]);
+        assert_eq!(r.count_ones(), 5);
+        assert!(r.get(63) && !r.get(62) && !r.get(70));
+        assert_eq!(BitRow::from_range(70, 60..66).count_ones(), 6);
+        assert!(!BitRow::new(70).any());
+    }
+
+    #[test]
+    fn operators_stay_within_the_width() {
+        let r = row();
+        assert_eq!((!&r).count_ones(), 65);
+        assert_eq!(!&!&r, r);
+        assert_eq!((&r << 1).iter_ones().collect::<Vec<_>>(), [1, 4, 64, 65]);
+        assert_eq!((&r >> 1).iter_ones().collect::<Vec<_>>(), [2, 62, 63, 68]);
+        assert_eq!((&r << 64).iter_ones().collect::<Vec<_>>(), [64, 67]);
+        assert_eq!((&r >> 64).iter_ones().collect::<Vec<_>>(), [0, 5]);
+        assert!(!(&r << 70).any());
+        let mask = BitRow::from_range(70, 0..64);
+        assert_eq!((&r & &mask).count_ones(), 3);
+        assert_eq!((&r | &mask).count_ones(), 66);
+        assert_eq!((&r ^ &mask).count_ones(), 63);
+    }
+
+    #[test]
+    fn shifts_across_words() {
+        // widths around one and two words, shifts within a word and by more
+        for width in [63, 64, 65, 128, 130] {
+            let r = BitRow::from_indices(width, (0..width).filter(|i| i % 3 == 0 || i % 7 == 1));
+            for n in [0, 1, 5, 63, 64, 65, 100, 129, 200] {
+                let left: Vec<usize> = r.iter_ones().map(|i| i + n).filter(|&i| i < width).collect();
+                let right: Vec<usize> = r.iter_ones().filter(|&i| i >= n).map(|i| i - n).collect();
+                assert_eq!((&r << n).iter_ones().collect::<Vec<_>>(), left, "{width} bits << {n}");
+                assert_eq!((&r >> n).iter_ones().collect::<Vec<_>>(), right, "{width} bits >> {n}");
+                // nothing left past the width for the next operations
+                assert_eq!((&r << n).count_ones(), left.len());
+            }
+        }
+        // the last bit of a word into the next one and back
+        let r = BitRow::from_indices(128, [63]);
+        assert_eq!((&r << 1).iter_ones().collect::<Vec<_>>(), [64]);
+        assert_eq!(&(&r << 1) >> 1, r);
+    }
+
+    #[test]
+    fn transpose_twice_is_identity() {
+        let b = BitBoard::from_fn(70, 3, |x, y| (x * 7 + y * 3) % 5 == 0);
+        let t = b.transpose();
+        assert_eq!((t.width(), t.height()), (3, 70));
+        for (x, y) in (0..70).flat_map(|x| (0..3).map(move |y| (x, y))) {
+            assert_eq!(t.get(y, x), b.get(x, y));
+        }
+        assert_eq!(t.transpose(), b);
+        assert_eq!(t.count_ones(), b.count_ones());
+        assert_eq!(b.column(2), *t.row(2));
+        let empty = BitBoard::new(0, 0);
+        assert_eq!(empty.transpose().transpose(), empty);
+    }
+
+    #[test]
+    fn board_from_grid() {
+        let grid = Grid::from_rows(vec![vec!['#', '.'], vec!['.', '#'], vec!['#', '#']]);
+        let b = BitBoard::from_grid(&grid, |&c| c == '#');
+        assert_eq!(format!("{b:?}"), "#.\n.#\n##\n");
+        assert_eq!(b.columns().map(|c| c.count_ones()).collect::<Vec<_>>(), [2, 2]);
+    }
+}
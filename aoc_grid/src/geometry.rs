@@ -0,0 +1,398 @@
+/*
+Directions and points of the map puzzles, instead of a Direction enum
+and tuple arithmetic in each day.
+
+The directions follow the grid convention: y grows downwards, so Up is
+a (0, -1) delta and turning right from Up goes to Right.
+  Direction4    Right, Down, Left, Up
+  Direction8    the same and the diagonals
+  Point2, Point3    coordinates with the arithmetic operators and the
+                    Manhattan and Chebyshev distances
+ */
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+// Change of direction, from one direction to the next
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Straight,
+    Right,
+    Back,
+    Left,
+}
+
+impl Direction4 {
+    // Clockwise from Right
+    pub const ALL: [Direction4; 4] = [Direction4::Right, Direction4::Down, Direction4::Left, Direction4::Up];
+
+    // Quarter turns clockwise from Right
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 3)
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    pub fn turn(self, t: Turn) -> Self {
+        Self::from_index(self.index() + t as usize)
+    }
+
+    // The turn going from this direction to `to`
+    pub fn turn_to(self, to: Direction4) -> Turn {
+        [Turn::Straight, Turn::Right, Turn::Back, Turn::Left][(to.index() + 4 - self.index()) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction4::Left | Direction4::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    pub fn dx(self) -> isize {
+        Direction8::from(self).dx()
+    }
+
+    pub fn dy(self) -> isize {
+        Direction8::from(self).dy()
+    }
+
+    // The move of one step in this direction
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        Direction8::from(self).delta()
+    }
+
+    // Direction of a delta along an axis, None for the others
+    pub fn from_delta(dx: isize, dy: isize) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| (d.dx(), d.dy()) == (dx, dy))
+    }
+
+    // One of the usual letters U, D, L, R or arrows ^ v < >
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'R' | '>' => Some(Direction4::Right),
+            'D' | 'v' => Some(Direction4::Down),
+            'L' | '<' => Some(Direction4::Left),
+            'U' | '^' => Some(Direction4::Up),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> char {
+        match self {
+            Direction4::Right => '>',
+            Direction4::Down => 'v',
+            Direction4::Left => '<',
+            Direction4::Up => '^',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    Up,
+    UpRight,
+}
+
+impl Direction8 {
+    // Clockwise from Right
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+        Direction8::Up,
+        Direction8::UpRight,
+    ];
+
+    fn from_index(i: usize) -> Self {
+        Self::ALL[i % 8]
+    }
+
+    // An eighth of turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self as usize + 1)
+    }
+
+    // An eighth of turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self as usize + 7)
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::from_index(self as usize + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn dx(self) -> isize {
+        match self {
+            Direction8::Right | Direction8::DownRight | Direction8::UpRight => 1,
+            Direction8::Left | Direction8::DownLeft | Direction8::UpLeft => -1,
+            Direction8::Down | Direction8::Up => 0,
+        }
+    }
+
+    pub fn dy(self) -> isize {
+        match self {
+            Direction8::Down | Direction8::DownRight | Direction8::DownLeft => 1,
+            Direction8::Up | Direction8::UpRight | Direction8::UpLeft => -1,
+            Direction8::Right | Direction8::Left => 0,
+        }
+    }
+
+    pub fn delta<T: From<i8>>(self) -> Point2<T> {
+        Point2::new(T::from(self.dx() as i8), T::from(self.dy() as i8))
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(d: Direction4) -> Self {
+        Self::ALL[d.index() * 2]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// |a - b| without a signed type, for the unsigned coordinates
+fn distance<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if a < b {
+        b
+    } else {
+        a
+    }
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point2<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    // Number of king moves, the diagonals counting as one
+    pub fn chebyshev(self, other: Self) -> T {
+        max(distance(self.x, other.x), distance(self.y, other.y))
+    }
+}
+
+impl Point2<usize> {
+    // The point one step away, None below 0
+    pub fn step(self, d: impl Into<Direction8>) -> Option<Self> {
+        let d = d.into();
+        Some(Self::new(self.x.checked_add_signed(d.dx())?, self.y.checked_add_signed(d.dy())?))
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    // Drops the z coordinate
+    pub fn xy(self) -> Point2<T> {
+        Point2::new(self.x, self.y)
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point3<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y) + distance(self.z, other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        max(max(distance(self.x, other.x), distance(self.y, other.y)), distance(self.z, other.z))
+    }
+}
+
+// The operators, coordinate by coordinate, and the product by a scalar
+macro_rules! point_ops {
+    ($p:ident, $($c:ident)+) => {
+        impl<T: Add<Output = T>> Add for $p<T> {
+            type Output = Self;
+            fn add(self, o: Self) -> Self {
+                $p { $($c: self.$c + o.$c),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $p<T> {
+            type Output = Self;
+            fn sub(self, o: Self) -> Self {
+                $p { $($c: self.$c - o.$c),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $p<T> {
+            fn add_assign(&mut self, o: Self) {
+                $(self.$c += o.$c;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $p<T> {
+            fn sub_assign(&mut self, o: Self) {
+                $(self.$c -= o.$c;)+
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $p<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                $p { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $p<T> {
+            type Output = Self;
+            fn mul(self, k: T) -> Self {
+                $p { $($c: self.$c * k),+ }
+            }
+        }
+    };
+}
+
+point_ops!(Point2, x y);
+point_ops!(Point3, x y z);
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_round_trip() {
+        for d in Direction4::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.turn_right().turn_right().turn_right().turn_right(), d);
+            assert_eq!(d.opposite().opposite(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opposite());
+            for t in [Turn::Straight, Turn::Right, Turn::Back, Turn::Left] {
+                assert_eq!(d.turn_to(d.turn(t)), t);
+            }
+            assert_eq!(Direction4::from_delta(d.dx(), d.dy()), Some(d));
+            assert_eq!(Direction4::from_char(d.to_arrow()), Some(d));
+            assert_eq!(d.delta::<i64>() + d.opposite().delta(), Point2::new(0, 0));
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.opposite().opposite(), d);
+            assert_eq!(d.turn_right().turn_right(), Direction8::from_index(d as usize + 2));
+        }
+    }
+
+    #[test]
+    fn map_convention() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!((Direction4::Up.dx(), Direction4::Up.dy()), (0, -1));
+        assert_eq!(Direction4::Right.turn_to(Direction4::Down), Turn::Right);
+        assert_eq!(Direction8::from(Direction4::Left), Direction8::Left);
+        assert!(Direction8::UpLeft.is_diagonal() && !Direction8::Up.is_diagonal());
+        assert_eq!(Direction4::from_delta(1, 1), None);
+    }
+
+    #[test]
+    fn points() {
+        let (a, b) = (Point2::new(1i64, -2), Point2::new(-3, 4));
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(-a * 3, Point2::new(-3, 6));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (10, 6));
+        let (p, q) = (Point2::new(5usize, 1), Point2::new(2, 7));
+        assert_eq!((p.manhattan(q), q.manhattan(p)), (9, 9));
+        assert_eq!(Point2::new(0usize, 3).step(Direction4::Left), None);
+        assert_eq!(Point2::new(0usize, 3).step(Direction8::DownRight), Some(Point2::new(1, 4)));
+        let c = Point3::new(1, 2, 3);
+        assert_eq!((c.manhattan(Point3::new(0, 0, 0)), c.chebyshev(Point3::new(0, 0, 0))), (6, 3));
+        assert_eq!(c.xy().to_string(), "1,2");
+    }
+}
@@ -0,0 +1,327 @@
+/*
+2D grid of tiles shared by the map puzzles, instead of a Vec<Vec<T>>
+indexed [y][x] or a custom copy of the same struct in each day.
+
+Positions are (x, y) with x the column and y the row, (0, 0) being the
+top-left tile. The accessors take any integer type for the coordinates:
+negative ones are outside of the grid like the too large ones, so a
+signed position can be checked without converting it first.
+  grid.get(x, y)              Option<&T>
+  grid.get_or(x, y, &t)       the given default outside of the grid
+  grid[(x, y)]                panics outside of the grid
+The directions and points of the geometry module, the bitboards of the
+bits module and the polygons of the polygon module are re-exported here.
+ */
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_common::parse::{self, ParseError};
+
+pub mod bits;
+pub mod geometry;
+pub mod polygon;
+pub use bits::{BitBoard, BitRow};
+pub use geometry::{Direction4, Direction8, Point2, Point3, Turn};
+pub use polygon::{Location, Orientation, Polygon};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // row after row
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, t0: T) -> Self {
+        Self { width, height, cells: vec![t0; width * height] }
+    }
+
+    // Swaps the rows and the columns
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |x, y| (y, x))
+    }
+
+    // Quarter turn clockwise: the left column becomes the top row
+    pub fn rotate_cw(&self) -> Self {
+        self.rebuild(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    // Quarter turn counterclockwise: the top row becomes the left column
+    pub fn rotate_ccw(&self) -> Self {
+        self.rebuild(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    // Mirror around the vertical axis: the left column becomes the right one
+    pub fn flip_horizontal(&self) -> Self {
+        self.rebuild(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    // Mirror around the horizontal axis: the top row becomes the bottom one
+    pub fn flip_vertical(&self) -> Self {
+        self.rebuild(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    // New grid of the given size, each tile copied from the position
+    // of this grid given by `from`
+    fn rebuild(&self, width: usize, height: usize, from: impl Fn(usize, usize) -> (usize, usize)) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[from(x, y)].clone());
+            }
+        }
+        Self { width, height, cells }
+    }
+
+    pub fn fill(&mut self, t: T) {
+        self.cells.fill(t);
+    }
+}
+
+impl<T> Grid<T> {
+    // Consumes the rows, which must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+        assert!(rows.iter().all(|r| r.len() == width), "the rows of a grid must have the same length");
+        Self { width, height, cells: rows.into_iter().flatten().collect() }
+    }
+
+    // Parses a map of one character per tile, `tile` returning None
+    // for the invalid characters. The empty lines are ignored.
+    pub fn parse<I, S>(lines: I, expected: &str, tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Ok(Self::from_rows(parse::grid(lines, expected, tile)?))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Index in the cells, None outside of the grid
+    fn offset(&self, x: impl TryInto<usize>, y: impl TryInto<usize>) -> Option<usize> {
+        let (x, y) = (x.try_into().ok()?, y.try_into().ok()?);
+        (x < self.width && y < self.height).then(|| x + y * self.width)
+    }
+
+    pub fn contains(&self, x: impl TryInto<usize>, y: impl TryInto<usize>) -> bool {
+        self.offset(x, y).is_some()
+    }
+
+    pub fn get(&self, x: impl TryInto<usize>, y: impl TryInto<usize>) -> Option<&T> {
+        self.offset(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: impl TryInto<usize>, y: impl TryInto<usize>) -> Option<&mut T> {
+        self.offset(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // For the puzzles where the outside is a known tile (ground, rock...)
+    pub fn get_or<'a>(&'a self, x: impl TryInto<usize>, y: impl TryInto<usize>, default: &'a T) -> &'a T {
+        self.get(x, y).unwrap_or(default)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, t: T) {
+        self[(x, y)] = t;
+    }
+
+    // The position after moving by (dx, dy), None when leaving the grid
+    pub fn step(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(x, y).then_some((x, y))
+    }
+
+    // The position one step away in direction d, None when leaving the grid
+    pub fn step_towards(&self, x: usize, y: usize, d: impl Into<Direction8>) -> Option<(usize, usize)> {
+        let d = d.into();
+        self.step(x, y, d.dx(), d.dy())
+    }
+
+    // The up to 4 adjacent positions within the grid: right, down, left, up
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction4::ALL.into_iter().filter_map(move |d| self.step_towards(x, y, d))
+    }
+
+    // Same with the diagonals, clockwise from the right
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction8::ALL.into_iter().filter_map(move |d| self.step_towards(x, y, d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        // chunks() refuses a 0 size, for an empty grid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl ExactSizeIterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {x} out of the grid");
+        (0..self.height).map(move |y| &self.cells[x + y * self.width])
+    }
+
+    pub fn columns(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    // All the positions, row after row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // The tiles with their position, row after row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    // Position of the first tile matching, row after row
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, t)| pred(t)).map(|(p, _)| p)
+    }
+
+    pub fn count(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+        self.cells.iter().filter(|t| pred(t)).count()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    // One line per row, for the debug logs
+    pub fn render<D: fmt::Display>(&self, mut tile: impl FnMut(&T) -> D) -> String {
+        let mut s = String::new();
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                s.push('\n');
+            }
+            for t in row {
+                s += &tile(t).to_string();
+            }
+        }
+        s
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.offset(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!("grid access {x},{y} out of bounds {}x{}", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.offset(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("grid access {x},{y} out of bounds {}x{}", self.width, self.height),
+        }
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2<usize>) -> &T {
+        &self[(p.x, p.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point2<usize>) -> &mut T {
+        &mut self[(p.x, p.y)]
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Grid {}x{}", self.width, self.height)?;
+        for row in self.rows() {
+            writeln!(f, "{row:?}")?;
+        }
+        Ok(())
+    }
+}
+
+// The char maps are printed as they were parsed
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 columns, 2 rows, no symmetry
+    fn sample() -> Grid<char> {
+        Grid::parse(["abc", "def"], "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn rotations_and_flips() {
+        let g = sample();
+        assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(g.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(g.rotate_cw().rotate_cw(), g.flip_horizontal().flip_vertical());
+        assert_eq!(g.rotate_cw(), g.transpose().flip_horizontal());
+    }
+
+    #[test]
+    fn round_trips_to_identity() {
+        let g = sample();
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.rotate_ccw().rotate_cw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+        assert_eq!(g.transpose().transpose(), g);
+        assert_eq!(g.flip_horizontal().flip_horizontal(), g);
+        assert_eq!(g.flip_vertical().flip_vertical(), g);
+        let empty = Grid::<char>::from_rows(Vec::new());
+        assert_eq!(empty.rotate_cw().transpose(), empty);
+    }
+
+    #[test]
+    fn accessors() {
+        let g = sample();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g.get(2, 1), Some(&'f'));
+        assert_eq!(g.get(-1, 0), None);
+        assert_eq!(g.get(3, 0), None);
+        assert_eq!(g.get_or(0i64, -1i64, &'.'), &'.');
+        assert_eq!(g[Point2::new(1, 0)], 'b');
+        assert_eq!(g.step(0, 0, -1, 0), None);
+        assert_eq!(g.step_towards(0, 0, Direction4::Down), Some((0, 1)));
+        assert_eq!(g.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(g.neighbours8(1, 0).count(), 5);
+        assert_eq!(g.column(1).collect::<String>(), "be");
+        assert_eq!(g.find(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_outside() {
+        let _ = sample()[(0, 2)];
+    }
+}
@@ -0,0 +1,202 @@
+/*
+Closed polygons with integer vertices: a loop of pipes, the path of a
+digger... Their area and the tiles they enclose are computed from the
+vertices only, whatever the size of the map.
+  Polygon::new(vertices)              the last vertex goes back to the first
+  Polygon::from_moves(start, moves)   from (direction, length) moves
+  signed_double_area()                shoelace formula, twice the area to stay exact
+  orientation()                       clockwise or counterclockwise on the map
+  boundary_points()                   lattice points on the edges
+  interior_points()                   lattice points strictly inside (Pick's theorem)
+  enclosed_points()                   both: the tiles of a loop with its inside
+  locate(p)                           inside, on the boundary or outside
+With the y axis going down like the rows of a map, a positive signed area
+is a clockwise polygon.
+ */
+
+use aoc_algo::number::gcd;
+
+use crate::{Direction4, Point2};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    Counterclockwise,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point2<i64>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2<i64>>) -> Self {
+        Self { vertices }
+    }
+
+    // The vertices reached by the moves from start. The last move should
+    // go back to the start, which is then not repeated.
+    pub fn from_moves(start: Point2<i64>, moves: impl IntoIterator<Item = (Direction4, i64)>) -> Self {
+        let mut vertices = vec![start];
+        let mut current = start;
+        for (d, len) in moves {
+            current += d.delta() * len;
+            vertices.push(current);
+        }
+        if vertices.len() > 1 && current == start {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2<i64>] {
+        &self.vertices
+    }
+
+    // The edges as (from, to), the last one closing the polygon
+    fn edges(&self) -> impl Iterator<Item = (Point2<i64>, Point2<i64>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    // Shoelace formula: positive for a clockwise polygon on the map
+    pub fn signed_double_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum()
+    }
+
+    pub fn double_area(&self) -> i64 {
+        self.signed_double_area().abs()
+    }
+
+    // None for a flat polygon
+    pub fn orientation(&self) -> Option<Orientation> {
+        match self.signed_double_area() {
+            0 => None,
+            a if a > 0 => Some(Orientation::Clockwise),
+            _ => Some(Orientation::Counterclockwise),
+        }
+    }
+
+    // Number of lattice points on the edges, the vertices included
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|(a, b)| gcd(b.x - a.x, b.y - a.y)).sum()
+    }
+
+    // Number of lattice points strictly inside, from Pick's theorem:
+    // area = interior + boundary / 2 - 1
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    // Number of lattice points inside or on the boundary
+    pub fn enclosed_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    // Crossing number of a horizontal ray from p towards the right,
+    // after checking p on each edge
+    pub fn locate(&self, p: Point2<i64>) -> Location {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let (ab, ap) = (b - a, p - a);
+            let cross = ab.x as i128 * ap.y as i128 - ab.y as i128 * ap.x as i128;
+            if cross == 0 && p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y) {
+                return Location::Boundary;
+            }
+            // the edge spans the line of p, counting its lower end only
+            if (a.y > p.y) != (b.y > p.y) {
+                // p is left of the edge when the cross product has the sign of ab.y
+                if (cross > 0) == (ab.y > 0) {
+                    inside = !inside;
+                }
+            }
+        }
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    pub fn contains(&self, p: Point2<i64>) -> bool {
+        self.locate(p) != Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i64, y: i64) -> Point2<i64> {
+        Point2::new(x, y)
+    }
+
+    // A 4x4 square with its bottom-right 2x2 quarter cut out
+    fn l_shape() -> Polygon {
+        Polygon::new(vec![p(0, 0), p(4, 0), p(4, 2), p(2, 2), p(2, 4), p(0, 4)])
+    }
+
+    #[test]
+    fn from_moves() {
+        use Direction4::*;
+        let moves = [(Right, 4), (Down, 2), (Left, 2), (Down, 2), (Left, 2), (Up, 4)];
+        assert_eq!(Polygon::from_moves(p(0, 0), moves), l_shape());
+    }
+
+    #[test]
+    fn shoelace_and_pick() {
+        let l = l_shape();
+        assert_eq!(l.signed_double_area(), 24);
+        assert_eq!(l.orientation(), Some(Orientation::Clockwise));
+        assert_eq!((l.boundary_points(), l.interior_points(), l.enclosed_points()), (16, 5, 21));
+        let reversed = Polygon::new(l.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.signed_double_area(), -24);
+        assert_eq!(reversed.orientation(), Some(Orientation::Counterclockwise));
+        assert_eq!(reversed.interior_points(), 5);
+        // slanted edges: the lattice points are counted with a gcd
+        let triangle = Polygon::new(vec![p(0, 0), p(6, 0), p(0, 4)]);
+        assert_eq!((triangle.double_area(), triangle.boundary_points(), triangle.interior_points()), (24, 12, 7));
+        assert_eq!(Polygon::new(vec![p(0, 0), p(3, 3)]).orientation(), None);
+    }
+
+    #[test]
+    fn locate_points() {
+        let l = l_shape();
+        // vertices, the inner corner included
+        for v in [p(0, 0), p(4, 2), p(2, 2), p(0, 4)] {
+            assert_eq!(l.locate(v), Location::Boundary, "{v}");
+        }
+        for b in [p(3, 0), p(4, 1), p(3, 2), p(2, 3), p(0, 1)] {
+            assert_eq!(l.locate(b), Location::Boundary, "{b}");
+        }
+        for i in [p(1, 1), p(3, 1), p(1, 2), p(1, 3)] {
+            assert_eq!(l.locate(i), Location::Inside, "{i}");
+        }
+        // in the cut out quarter, and with rays through vertices and
+        // along horizontal edges
+        for o in [p(3, 3), p(5, 1), p(-1, 0), p(-1, 2), p(-1, 4), p(1, 5), p(10, -3)] {
+            assert_eq!(l.locate(o), Location::Outside, "{o}");
+        }
+        assert!(l.contains(p(2, 4)) && !l.contains(p(4, 4)));
+    }
+
+    #[test]
+    fn locate_agrees_with_pick() {
+        let shapes = [l_shape(), Polygon::new(vec![p(0, 0), p(6, 0), p(0, 4)])];
+        for shape in shapes {
+            let points: Vec<Location> = (-2..8).flat_map(|y| (-2..8).map(move |x| p(x, y)))
+                .map(|q| shape.locate(q))
+                .collect();
+            let count = |loc| points.iter().filter(|&&l| l == loc).count() as i64;
+            assert_eq!(count(Location::Inside), shape.interior_points());
+            assert_eq!(count(Location::Boundary), shape.boundary_points());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+/*
+https://adventofcode.com/2023/day/1
+--- Day 1: Trebuchet?! ---
+
+Both parts: the calibration value of a line is made of its first and
+last digits, only the ascii ones for part 1 and also the spelled-out
+ones for part 2 (see the scanner module for other vocabularies).
+
+--explain (or --param explain=1) logs why each line gives its value,
+shown at the info level: the first and last tokens with their offsets,
+and whether they are the same token or overlap like "eightwo".
+ */
+
+use std::io::{self, BufRead};
+use aoc_common::ParseError;
+use aoc_common::{debug, info, trace};
+use aoc_common::Answer;
+
+pub mod scanner;
+use scanner::{Match, Scanner, Vocabulary};
+
+struct Solver {
+    total_calibration: i32,
+    scanner: Scanner,
+    // for the error of the lines without any digit
+    expected: &'static str,
+    explain: bool,
+    line: usize,
+}
+
+impl Solver {
+    fn new(vocabulary: &Vocabulary, part_2: bool, explain: bool) -> Self {
+        Self{total_calibration : 0,
+             scanner : Scanner::new(vocabulary),
+             expected : if part_2 { "a digit or a spelled-out digit" } else { "a digit" },
+             explain,
+             line : 0,
+        }
+    }
+}
+
+// One line of the --explain report
+fn explain(n: usize, input: &str, first: &Match, last: &Match, value: u32) -> String {
+    let token = |m: &Match| format!("'{}' at {}..{}", &input[m.start..m.end], m.start, m.end);
+    let note = if first == last {
+        " (same token)"
+    } else if first.overlaps(last) {
+        " (overlapping)"
+    } else {
+        ""
+    };
+    format!("line {n}: {value} = first {} + last {}{note}  {input}", token(first), token(last))
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, input: &str) -> Result<(), ParseError> {
+        // Find the 1st and last digit of the string (it can be the same
+        // character if it's the only one) and concatenate them.
+        // All the tokens are found in one pass, even when they
+        // overlap like "eightwo", so the first and last are the
+        // ones starting first and last.
+        self.line += 1;
+        debug!(" parsing input-line {} ", input);
+
+        let matches = self.scanner.matches(input);
+        let (Some(first), Some(last)) = (matches.first(), matches.last()) else {
+            return Err(ParseError::new(1, self.expected, input));
+        };
+        for m in &matches {
+            trace!(" found {} at {}..{} ", &input[m.start..m.end], m.start, m.end);
+        }
+
+        let value = first.value * 10 + last.value;
+        debug!("parsed calibration value {}", value);
+        if self.explain {
+            info!("{}", explain(self.line, input, first, last, value));
+        }
+        self.total_calibration += value as i32;
+        Ok(())
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total_calibration.into()
+    }
+}
+
+pub const PARAMS: &[aoc_common::Param] = &[aoc_common::Param {
+    name: "explain",
+    help: "log the first and last tokens of each line",
+    min: 0,
+    max: 1,
+}];
+
+// The tokens of the calibration values: the digits, and the
+// english words too for part 2
+pub fn vocabulary(part_2: bool) -> Vocabulary {
+    if part_2 { Vocabulary::english() } else { Vocabulary::digits() }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    run_with(reader, params, &vocabulary(params.part_2()))
+}
+
+// Same with another vocabulary, e.g. Vocabulary::french() or one
+// parsed from a file
+pub fn run_with(reader: &mut dyn BufRead, params: &aoc_common::Params, vocabulary: &Vocabulary) -> io::Result<Answer> {
+    if params.switch("explain") {
+        aoc_common::log::show_module(module_path!(), aoc_common::log::Level::Info);
+    }
+    aoc_common::run(Solver::new(vocabulary, params.part_2(), params.switch("explain")), reader)
+}
+
+pub fn solve_a(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
+
+pub fn solve_b(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new_part_2(PARAMS))
+}
+
+pub fn solve_with(input: &str, vocabulary: &Vocabulary) -> io::Result<Answer> {
+    run_with(&mut input.as_bytes(), &aoc_common::Params::new_part_2(PARAMS), vocabulary)
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::main_with_params(day_01::PARAMS, day_01::run);
+}
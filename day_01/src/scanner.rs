@@ -0,0 +1,261 @@
+/*
+Multi-pattern scanner of the calibration lines: an Aho-Corasick automaton
+built from a vocabulary of tokens, finding all their matches in a single
+pass over the line, the overlapping ones included ("eightwo" is both 8
+and 2), in a time linear in the length of the line.
+  Vocabulary::digits()          "0" to "9", for part 1
+  Vocabulary::english()         the digits and "one" to "nine", for part 2
+  Vocabulary::french()          the digits and "un" to "neuf"
+  Vocabulary::digits_and(&v)    the digits and the tokens of v
+  Vocabulary::parse(text)       "word=digit" entries, separated by commas,
+                                spaces or lines, e.g. "uno=1, dos=2"
+  Scanner::new(&vocabulary)
+  scanner.matches(line)         every Match with its position in the line
+ */
+
+use std::collections::VecDeque;
+use aoc_common::parse::{self, ParseError};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>,
+}
+
+const ENGLISH: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const FRENCH: [&str; 9] = ["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"];
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self { tokens: Vec::new() }
+    }
+
+    pub fn digits() -> Self {
+        let mut v = Self::new();
+        for d in 0..=9 {
+            v.add(&d.to_string(), d);
+        }
+        v
+    }
+
+    fn digits_and_words(words: &[&str; 9]) -> Self {
+        let mut v = Self::digits();
+        for (d, w) in (1..).zip(words) {
+            v.add(w, d);
+        }
+        v
+    }
+
+    // The words of a vocabulary file, the digits being always recognized
+    pub fn digits_and(words: &Vocabulary) -> Self {
+        let mut v = Self::digits();
+        for (token, value) in &words.tokens {
+            v.add(token, *value);
+        }
+        v
+    }
+
+    pub fn english() -> Self {
+        Self::digits_and_words(&ENGLISH)
+    }
+
+    pub fn french() -> Self {
+        Self::digits_and_words(&FRENCH)
+    }
+
+    // Replaces the value of a token already there
+    pub fn add(&mut self, token: &str, value: u32) -> &mut Self {
+        assert!(!token.is_empty(), "empty token in a vocabulary");
+        match self.tokens.iter_mut().find(|(t, _)| t == token) {
+            Some(entry) => entry.1 = value,
+            None => self.tokens.push((token.to_string(), value)),
+        }
+        self
+    }
+
+    // "word=digit" entries separated by commas, spaces or lines
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut v = Self::new();
+        for (n, line) in text.lines().enumerate() {
+            let at_line = |e: ParseError| e.with_line(n + 1);
+            for item in parse::items(line) {
+                let (token, value) = parse::pair(line, item, "=", "<word>=<digit>").map_err(at_line)?;
+                if token.is_empty() {
+                    return Err(at_line(ParseError::at(line, item, "<word>=<digit>")));
+                }
+                let value: u32 = parse::number(line, value).map_err(at_line)?;
+                if value > 9 {
+                    return Err(at_line(ParseError::at(line, item, "a digit 0 to 9")));
+                }
+                v.add(token, value);
+            }
+        }
+        Ok(v)
+    }
+
+    pub fn tokens(&self) -> &[(String, u32)] {
+        &self.tokens
+    }
+}
+
+// A token found in a line, &line[start..end]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+impl Match {
+    // "eightwo": eight and two share the 't'
+    pub fn overlaps(&self, other: &Match) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+// A state of the automaton: the prefix of some tokens read so far
+#[derive(Default)]
+struct State {
+    next: Vec<(u8, usize)>,
+    // the state of the longest proper suffix which is also a prefix
+    fail: usize,
+    // the tokens ending here, this one and the ones of its suffixes
+    tokens: Vec<usize>,
+}
+
+impl State {
+    fn goto(&self, b: u8) -> Option<usize> {
+        self.next.iter().find(|&&(c, _)| c == b).map(|&(_, s)| s)
+    }
+}
+
+pub struct Scanner {
+    tokens: Vec<(String, u32)>,
+    // 0 is the root, the empty prefix
+    states: Vec<State>,
+}
+
+impl Scanner {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let mut states = vec![State::default()];
+
+        // the trie of the tokens
+        for (k, (token, _)) in vocabulary.tokens.iter().enumerate() {
+            let mut s = 0;
+            for &b in token.as_bytes() {
+                s = match states[s].goto(b) {
+                    Some(t) => t,
+                    None => {
+                        states.push(State::default());
+                        let t = states.len() - 1;
+                        states[s].next.push((b, t));
+                        t
+                    }
+                };
+            }
+            states[s].tokens.push(k);
+        }
+
+        // the failure links, breadth first so that the shorter prefixes
+        // are done before the longer ones
+        let mut queue: VecDeque<usize> = states[0].next.iter().map(|&(_, t)| t).collect();
+        while let Some(s) = queue.pop_front() {
+            for (b, t) in states[s].next.clone() {
+                let mut f = states[s].fail;
+                let fail = loop {
+                    if let Some(g) = states[f].goto(b) {
+                        break g;
+                    }
+                    if f == 0 {
+                        break 0;
+                    }
+                    f = states[f].fail;
+                };
+                states[t].fail = fail;
+                let inherited = states[fail].tokens.clone();
+                states[t].tokens.extend(inherited);
+                queue.push_back(t);
+            }
+        }
+
+        Self { tokens: vocabulary.tokens.clone(), states }
+    }
+
+    // All the tokens found in the line, ordered by position
+    pub fn matches(&self, line: &str) -> Vec<Match> {
+        let mut found = Vec::new();
+        let mut s = 0;
+        for (i, &b) in line.as_bytes().iter().enumerate() {
+            while s != 0 && self.states[s].goto(b).is_none() {
+                s = self.states[s].fail;
+            }
+            s = self.states[s].goto(b).unwrap_or(0);
+            for &k in &self.states[s].tokens {
+                let (token, value) = &self.tokens[k];
+                found.push(Match { start: i + 1 - token.len(), end: i + 1, value: *value });
+            }
+        }
+        found.sort_by_key(|m| (m.start, m.end));
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (start, end, value) of each match
+    fn found(vocabulary: &Vocabulary, line: &str) -> Vec<(usize, usize, u32)> {
+        Scanner::new(vocabulary).matches(line).iter().map(|m| (m.start, m.end, m.value)).collect()
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let english = Vocabulary::english();
+        assert_eq!(found(&english, "eightwo"), vec![(0, 5, 8), (4, 7, 2)]);
+        assert_eq!(found(&english, "oneight"), vec![(0, 3, 1), (2, 7, 8)]);
+        assert_eq!(found(&english, "x2nineight3"), vec![(1, 2, 2), (2, 6, 9), (5, 10, 8), (10, 11, 3)]);
+        assert_eq!(found(&Vocabulary::digits(), "eightwo"), vec![]);
+
+        let m = Scanner::new(&english).matches("eightwo");
+        assert!(m[0].overlaps(&m[1]));
+        assert!(!Match { start: 0, end: 3, value: 1 }.overlaps(&Match { start: 3, end: 6, value: 2 }));
+    }
+
+    #[test]
+    fn suffix_tokens() {
+        // "he" ends within "she", found through the failure link of "sh"
+        let mut v = Vocabulary::new();
+        v.add("she", 1).add("he", 2).add("hers", 3);
+        assert_eq!(found(&v, "ushers"), vec![(1, 4, 1), (2, 4, 2), (2, 6, 3)]);
+        // "hhe": the failure from "h" on 'h' back to "h"
+        assert_eq!(found(&v, "hhe"), vec![(1, 3, 2)]);
+    }
+
+    #[test]
+    fn french() {
+        let french = Vocabulary::french();
+        assert_eq!(french.tokens().len(), 19);
+        assert_eq!(found(&french, "deuxtrois5"), vec![(0, 4, 2), (4, 9, 3), (9, 10, 5)]);
+        // "six" is the same in both, "cinq" has no english part
+        assert_eq!(found(&french, "sixcinqone"), vec![(0, 3, 6), (3, 7, 5)]);
+    }
+
+    #[test]
+    fn parsed_vocabulary() {
+        let v = Vocabulary::parse("uno=1, dos=2\ntres=3 uno=4").unwrap();
+        // the value of uno replaced
+        assert_eq!(v.tokens(), &[("uno".to_string(), 4), ("dos".to_string(), 2), ("tres".to_string(), 3)]);
+        let v = Vocabulary::digits_and(&v);
+        assert_eq!(v.tokens().len(), 13);
+        assert_eq!(found(&v, "dos7uno"), vec![(0, 3, 2), (3, 4, 7), (4, 7, 4)]);
+    }
+
+    #[test]
+    fn vocabulary_errors() {
+        let err = |text| Vocabulary::parse(text).unwrap_err().to_string();
+        assert_eq!(err("uno=1\ndos=2, x=10"), "line 2, column 8: expected a digit 0 to 9, found 'x=10'");
+        assert_eq!(err("=3"), "line 1, column 1: expected <word>=<digit>, found '=3'");
+        assert_eq!(err("uno=1 dos"), "line 1, column 7: expected <word>=<digit>, found 'dos'");
+        assert_eq!(err("uno=one"), "line 1, column 5: expected a number, found 'one'");
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::main_with_params(day_02::part_a::PARAMS, day_02::part_a::run);
+}
@@ -0,0 +1,230 @@
+/*
+The cube games, parsed once into a typed model answering the questions
+of both parts and the other ones about the same records. The colours
+are any names found in the input, not only red, green and blue.
+  Game::parse(line)               "Game 12: 3 blue, 4 red; 1 red, 2 green"
+  parse_games(text)               one game per line
+  Bag::from([("red", 12), ...])   the cubes of each colour
+  game.is_possible_with(&bag)     no draw showing more cubes than the bag
+  game.minimal_bag()              the fewest cubes of each colour
+  game.power()                    product of the counts of the minimal bag
+  game.power_over(&colours)       the same over given colours, 0 if one is never drawn
+  game.most_constraining(&bag)    the colour with the smallest margin
+  possible_games(&games, &bag)    the games possible with the bag
+ */
+
+use std::collections::BTreeMap;
+use std::fmt;
+use aoc_common::parse::{self, ParseError};
+
+// Number of cubes of each colour, the missing colours having 0
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+// The cubes shown in a round, or the content of a bag
+pub type Draw = Cubes;
+pub type Bag = Cubes;
+
+impl Cubes {
+    pub fn new() -> Self {
+        Self { counts: BTreeMap::new() }
+    }
+
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, colour: &str, count: u32) {
+        self.counts.insert(colour.to_string(), count);
+    }
+
+    // The colours with their count, by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+        self.counts.iter().map(|(c, &n)| (c.as_str(), n))
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.values().sum()
+    }
+
+    // true when each colour of other has at most as many cubes here
+    pub fn contains(&self, other: &Cubes) -> bool {
+        other.iter().all(|(c, n)| n <= self.count(c))
+    }
+
+    // The largest count of each colour of both
+    pub fn max(&self, other: &Cubes) -> Cubes {
+        let mut m = self.clone();
+        for (c, n) in other.iter() {
+            if n > m.count(c) {
+                m.set(c, n);
+            }
+        }
+        m
+    }
+
+    // Product of the counts of the colours
+    pub fn power(&self) -> u64 {
+        self.counts.values().map(|&n| n as u64).product()
+    }
+
+    // Product of the counts of the given colours, the missing ones having 0
+    pub fn power_over(&self, colours: &[&str]) -> u64 {
+        colours.iter().map(|c| self.count(c) as u64).product()
+    }
+
+    // "3 blue, 4 red", `part` being a slice of `line`
+    pub fn parse(line: &str, part: &str) -> Result<Self, ParseError> {
+        let mut cubes = Cubes::new();
+        for c in part.split(',') {
+            let (count, colour) = parse::pair(line, c, " ", "<count> <colour>")?;
+            let count = parse::number::<u32>(line, count)?;
+            // the same colour twice in a round is unusual, but adds up
+            cubes.set(colour, cubes.count(colour) + count);
+        }
+        Ok(cubes)
+    }
+}
+
+impl<const N: usize> From<[(&str, u32); N]> for Cubes {
+    fn from(counts: [(&str, u32); N]) -> Self {
+        Self { counts: counts.iter().map(|&(c, n)| (c.to_string(), n)).collect() }
+    }
+}
+
+// "14 blue, 13 green, 12 red", like a draw
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (c, n)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{n} {c}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Draw>,
+}
+
+impl Game {
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let (id, rounds) = parse::record::<u32>(line, "Game")?;
+        let rounds = rounds.split(';').map(|r| Draw::parse(line, r)).collect::<Result<_, _>>()?;
+        Ok(Self { id, rounds })
+    }
+
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.rounds.iter().all(|d| bag.contains(d))
+    }
+
+    pub fn minimal_bag(&self) -> Bag {
+        self.rounds.iter().fold(Bag::new(), |bag, d| bag.max(d))
+    }
+
+    // The power of the minimal bag, over its colours only
+    pub fn power(&self) -> u64 {
+        self.minimal_bag().power()
+    }
+
+    // The power of the minimal bag over the given colours, 0 when one of
+    // them is never drawn
+    pub fn power_over(&self, colours: &[&str]) -> u64 {
+        self.minimal_bag().power_over(colours)
+    }
+
+    // The colour with the fewest cubes left in the bag after the largest
+    // draw of the game, negative when the game is impossible with it.
+    // None when the game and the bag have no colour.
+    pub fn most_constraining(&self, bag: &Bag) -> Option<(String, i64)> {
+        let needed = self.minimal_bag();
+        bag.max(&needed).iter()
+            .map(|(c, _)| (c.to_string(), bag.count(c) as i64 - needed.count(c) as i64))
+            .min_by_key(|(_, margin)| *margin)
+    }
+}
+
+// One game per line, the errors with their line number
+pub fn parse_games(text: &str) -> Result<Vec<Game>, ParseError> {
+    text.lines().enumerate()
+        .map(|(n, l)| Game::parse(l).map_err(|e| e.with_line(n + 1)))
+        .collect()
+}
+
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Bag) -> impl Iterator<Item = &'a Game> + 'a {
+    games.iter().filter(move |g| g.is_possible_with(bag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+
+    #[test]
+    fn any_colour() {
+        let g = Game::parse("Game 7: 2 yellow, 1 red; 5 yellow").unwrap();
+        assert_eq!(g.id, 7);
+        assert_eq!(g.rounds, vec![Draw::from([("yellow", 2), ("red", 1)]), Draw::from([("yellow", 5)])]);
+        assert_eq!(g.rounds[0].to_string(), "1 red, 2 yellow");
+    }
+
+    #[test]
+    fn repeated_colour_adds_up() {
+        let g = Game::parse("Game 1: 2 red, 3 blue, 4 red").unwrap();
+        assert_eq!(g.rounds, vec![Draw::from([("red", 6), ("blue", 3)])]);
+    }
+
+    #[test]
+    fn minimal_bag_and_power() {
+        let games = parse_games(SAMPLE).unwrap();
+        assert_eq!(games[0].minimal_bag(), Bag::from([("red", 4), ("green", 2), ("blue", 6)]));
+        assert_eq!(games.iter().map(|g| g.power()).collect::<Vec<_>>(), vec![48, 12, 1560]);
+
+        // green is never drawn: left out of the power, 0 over the three colours
+        let g = Game::parse("Game 1: 3 blue, 4 red; 1 red, 6 blue").unwrap();
+        assert_eq!(g.power(), 24);
+        assert_eq!(g.power_over(&["red", "green", "blue"]), 0);
+        assert_eq!(g.power_over(&["red", "blue"]), 24);
+    }
+
+    #[test]
+    fn most_constraining() {
+        let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+        let games = parse_games(SAMPLE).unwrap();
+        assert_eq!(games[0].most_constraining(&bag), Some(("blue".to_string(), 8)));
+        // 20 red out of 12
+        assert_eq!(games[2].most_constraining(&bag), Some(("red".to_string(), -8)));
+        // a colour missing from the bag
+        let g = Game::parse("Game 4: 1 yellow, 12 red").unwrap();
+        assert_eq!(g.most_constraining(&bag), Some(("yellow".to_string(), -1)));
+
+        let empty = Game { id: 5, rounds: vec![] };
+        assert_eq!(empty.most_constraining(&Bag::new()), None);
+        assert_eq!(empty.most_constraining(&bag), Some(("red".to_string(), 12)));
+    }
+
+    #[test]
+    fn possible() {
+        let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+        let games = parse_games(SAMPLE).unwrap();
+        let ids: Vec<u32> = possible_games(&games, &bag).map(|g| g.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(possible_games(&games, &Bag::new()).count(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_games("Game 1: 1 red\nGame 2: x red").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 9: expected a number, found 'x'");
+    }
+}
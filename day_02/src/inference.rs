@@ -0,0 +1,174 @@
+/*
+What the draws of a game tell about the unknown bag: every composition
+of at most `budget` cubes able to give each draw, and how likely each
+one is. The cubes of a draw are taken together without replacement,
+then put back in the bag before the next round.
+  consistent_bags(&game, &colours, budget)   the bags able to give the draws
+  likelihood(&game, &bag)                    P(draws | bag)
+  infer(&game, &colours, budget)             the consistent bags with their
+                                             probability, the likeliest first
+The colours are the ones of the game and the extra ones given, which may
+be in the bag without ever being drawn. The probabilities assume all the
+consistent bags equally likely before the draws.
+The number of bags grows like budget^colours: past MAX_BAGS, they are
+counted but not enumerated, and both functions return None.
+ */
+
+use crate::game::{Bag, Game};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estimate {
+    pub bag: Bag,
+    pub likelihood: f64,
+    // the likelihood normalized over all the consistent bags
+    pub probability: f64,
+}
+
+// The most bags enumerated for a game
+pub const MAX_BAGS: usize = 100_000;
+
+// Every bag of at most budget cubes of the game colours and the other
+// ones, containing each draw. None when there are more than MAX_BAGS.
+pub fn consistent_bags(game: &Game, colours: &[&str], budget: u32) -> Option<Vec<Bag>> {
+    let mut minimal = game.minimal_bag();
+    for c in colours {
+        minimal.set(c, minimal.count(c));
+    }
+    let colours: Vec<(&str, u32)> = minimal.iter().collect();
+    let mut bags = Vec::new();
+    if minimal.total() <= budget {
+        let spare = budget - minimal.total();
+        bags.reserve(count_bags(colours.len(), spare)?);
+        add_cubes(&colours, spare, &mut minimal.clone(), &mut bags);
+    }
+    Some(bags)
+}
+
+// Number of ways of adding at most spare cubes to k colours, C(spare + k, k),
+// None past MAX_BAGS
+fn count_bags(k: usize, spare: u32) -> Option<usize> {
+    let mut count: u128 = 1;
+    // C(spare + i, i) from C(spare + i - 1, i - 1), growing with i
+    for i in 1..=k as u128 {
+        count = count * (spare as u128 + i) / i;
+        if count > MAX_BAGS as u128 {
+            return None;
+        }
+    }
+    Some(count as usize)
+}
+
+// Each way of adding at most spare cubes to the colours, from the first
+fn add_cubes(colours: &[(&str, u32)], spare: u32, bag: &mut Bag, bags: &mut Vec<Bag>) {
+    let Some((&(c, min), rest)) = colours.split_first() else {
+        bags.push(bag.clone());
+        return;
+    };
+    for extra in 0..=spare {
+        bag.set(c, min + extra);
+        add_cubes(rest, spare - extra, bag, bags);
+    }
+    bag.set(c, min);
+}
+
+// C(n, k) as a float, the counts being too large for the integers soon
+fn binomial(n: u32, k: u32) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |b, i| b * (n - i) as f64 / (i + 1) as f64)
+}
+
+// Probability of the draws out of this bag: for each round, the ways of
+// drawing its cubes of each colour over the ways of drawing as many
+// cubes (multivariate hypergeometric)
+pub fn likelihood(game: &Game, bag: &Bag) -> f64 {
+    game.rounds.iter()
+        .map(|draw| {
+            let ways: f64 = draw.iter().map(|(c, n)| binomial(bag.count(c), n)).product();
+            ways / binomial(bag.total(), draw.total())
+        })
+        .product()
+}
+
+pub fn infer(game: &Game, colours: &[&str], budget: u32) -> Option<Vec<Estimate>> {
+    let mut estimates: Vec<Estimate> = consistent_bags(game, colours, budget)?.into_iter()
+        .map(|bag| {
+            let likelihood = likelihood(game, &bag);
+            Estimate { bag, likelihood, probability: 0.0 }
+        })
+        .collect();
+    let sum: f64 = estimates.iter().map(|e| e.likelihood).sum();
+    if sum > 0.0 {
+        for e in &mut estimates {
+            e.probability = e.likelihood / sum;
+        }
+    }
+    // stable: the equally likely bags stay in the enumeration order
+    estimates.sort_by(|a, b| b.likelihood.total_cmp(&a.likelihood));
+    Some(estimates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(l: &str) -> Game {
+        Game::parse(l).unwrap()
+    }
+
+    #[test]
+    fn bag_counts() {
+        // C(spare + k, k)
+        assert_eq!(count_bags(0, 5), Some(1));
+        assert_eq!(count_bags(1, 5), Some(6));
+        assert_eq!(count_bags(2, 4), Some(15));
+        assert_eq!(count_bags(3, 2), Some(10));
+        // C(85, 3) = 98770 and C(86, 3) = 102340, past MAX_BAGS
+        assert_eq!(count_bags(3, 82), Some(98_770));
+        assert_eq!(count_bags(3, 83), None);
+    }
+
+    #[test]
+    fn bags_with_colours_never_drawn() {
+        let g = game("Game 1: 2 red; 1 red");
+        let bags = consistent_bags(&g, &["red", "green"], 3).unwrap();
+        let counts: Vec<(u32, u32)> = bags.iter().map(|b| (b.count("red"), b.count("green"))).collect();
+        // enumerated from the first colour by name, green
+        assert_eq!(counts, vec![(2, 0), (3, 0), (2, 1)]);
+
+        assert_eq!(consistent_bags(&g, &["red"], 2).unwrap().len(), 1);
+        // the minimal bag is over the budget
+        assert_eq!(consistent_bags(&g, &["red"], 1), Some(vec![]));
+        // 2 + 83 cubes over 3 colours
+        assert_eq!(consistent_bags(&g, &["red", "green", "blue"], 85), None);
+    }
+
+    #[test]
+    fn hypergeometric_likelihood() {
+        let bag = Bag::from([("red", 2), ("blue", 2)]);
+        assert_eq!(likelihood(&game("Game 1: 1 red"), &bag), 0.5);
+        // C(2, 1) C(2, 1) / C(4, 2) = 4 / 6
+        assert!((likelihood(&game("Game 1: 1 red, 1 blue"), &bag) - 2.0 / 3.0).abs() < 1e-12);
+        // the cubes are put back between the rounds
+        assert!((likelihood(&game("Game 1: 1 red; 1 red, 1 blue"), &bag) - 1.0 / 3.0).abs() < 1e-12);
+        // more cubes drawn than in the bag
+        assert_eq!(likelihood(&game("Game 1: 3 red"), &bag), 0.0);
+    }
+
+    #[test]
+    fn infer_normalized_and_sorted() {
+        let g = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        // 12 cubes at least, 2 spare over 3 colours: C(5, 3) bags
+        let estimates = infer(&g, &["red", "green", "blue"], 14).unwrap();
+        assert_eq!(estimates.len(), 10);
+        let sum: f64 = estimates.iter().map(|e| e.probability).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+        assert!(estimates.windows(2).all(|w| w[0].likelihood >= w[1].likelihood));
+        assert!((estimates[0].probability - 0.1796).abs() < 1e-4);
+        assert!(estimates.iter().all(|e| g.is_possible_with(&e.bag)));
+
+        assert_eq!(infer(&g, &[], 11), Some(vec![]));
+    }
+}
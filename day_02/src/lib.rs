@@ -0,0 +1,9 @@
+/*
+https://adventofcode.com/2023/day/2
+--- Day 2: Cube Conundrum ---
+ */
+
+pub mod game;
+pub mod inference;
+pub mod part_a;
+pub mod part_b;
@@ -0,0 +1,98 @@
+/*
+https://adventofcode.com/2023/day/2
+--- Day 2: Cube Conundrum ---
+
+--param budget=N also logs at the info level, for each game, how many
+bags of at most N cubes are consistent with its draws and the likeliest
+ones (see the inference module).
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::ParseError;
+use aoc_common::{debug, info, warn};
+use aoc_common::Answer;
+use crate::game::{self, Bag, Game};
+use crate::inference;
+
+struct Solver {
+    bag: Bag,
+    games: Vec<Game>,
+    total_ids: u32,
+    // 0 when not inferring the bags
+    budget: u32,
+}
+
+// The likeliest bags logged for each game
+const LIKELIEST: usize = 3;
+
+impl Solver {
+    fn new(budget: u32) -> Self {
+        Self{bag: Bag::from([("red", 12), ("green", 13), ("blue", 14)]),
+             games: Vec::new(),
+             total_ids: 0,
+             budget,
+        }
+    }
+
+    // The bags of at most budget cubes able to give the draws of the game,
+    // red, green and blue included even when not drawn
+    fn report_bags(&self, g: &Game) {
+        let Some(estimates) = inference::infer(g, &["red", "green", "blue"], self.budget) else {
+            warn!("game {}: more than {} bags of at most {} cubes, not inferred",
+                  g.id, inference::MAX_BAGS, self.budget);
+            return;
+        };
+        info!("game {}: {} bags of at most {} cubes", g.id, estimates.len(), self.budget);
+        for e in estimates.iter().take(LIKELIEST) {
+            info!("  p = {:.4}  {}", e.probability, e.bag);
+        }
+        for e in estimates.iter().skip(LIKELIEST) {
+            debug!("  p = {:.4}  {}", e.probability, e.bag);
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        self.games.push(Game::parse(l)?);
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        self.total_ids = game::possible_games(&self.games, &self.bag).map(|g| g.id).sum();
+        for g in &self.games {
+            if let Some((colour, margin)) = g.most_constraining(&self.bag).filter(|&(_, m)| m < 0) {
+                debug!("game id {} impossible, {} short of {}", g.id, -margin, colour);
+            }
+            if self.budget > 0 {
+                self.report_bags(g);
+            }
+        }
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total_ids.into()
+    }
+}
+
+pub const PARAMS: &[aoc_common::Param] = &[aoc_common::Param {
+    name: "budget",
+    help: "log the likeliest bags of at most this many cubes for each game (default 0, none)",
+    min: 0,
+    max: 1000,
+}];
+
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    let budget = params.get("budget").unwrap_or(0);
+    if budget > 0 {
+        aoc_common::log::show_module(module_path!(), aoc_common::log::Level::Info);
+    }
+    aoc_common::run(Solver::new(budget as u32), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
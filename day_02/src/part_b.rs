@@ -0,0 +1,42 @@
+/*
+https://adventofcode.com/2023/day/2
+--- Day 2: Cube Conundrum ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::ParseError;
+use aoc_common::debug;
+use aoc_common::Answer;
+use crate::game::Game;
+
+#[derive(Default)]
+struct Solver {
+    total_powers: u64,
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        let game = Game::parse(l)?;
+        // the power over red, green and blue only, a colour never drawn
+        // making it 0
+        let power = game.power_over(&["red", "green", "blue"]);
+        debug!("game id {} power = {}", game.id, power);
+        self.total_powers += power;
+        Ok(())
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total_powers.into()
+    }
+}
+
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::default(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,252 @@
+/*
+Streaming analyzer of the schematic, for both parts in one pass: only
+three rows are kept, the one above, the current one and the one just
+read, so the memory stays the same whatever the size of the schematic.
+A row is final once the row below it is read (or at the end), and its
+part numbers and gears are then emitted.
+  Analyzer::new()
+  analyzer.push(line)       the events of the row made final by this one
+  analyzer.finish()         the events of the last row
+  Event::Part { .. }        a number next to a symbol, for part 1
+  Event::Gear { .. }        a '*' next to exactly two numbers, for part 2
+ */
+
+use std::io::{self, BufRead};
+use std::ops::Range;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+// Rows numbered from 1, like the lines of the input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    Part { row: usize, value: u32 },
+    Gear { row: usize, x: usize, ratio: u64 },
+}
+
+// The numbers and symbols of a row, by position
+#[derive(Default)]
+struct Row {
+    numbers: Vec<(u32, Range<usize>)>,
+    symbols: Vec<(usize, u8)>,
+}
+
+impl Row {
+    fn parse(l: &str) -> Result<Self, ParseError> {
+        let mut row = Row::default();
+        let bytes = l.as_bytes();
+        let mut x = 0;
+        while x < bytes.len() {
+            if bytes[x].is_ascii_digit() {
+                let start = x;
+                while x < bytes.len() && bytes[x].is_ascii_digit() {
+                    x += 1;
+                }
+                row.numbers.push((parse::number(l, &l[start..x])?, start..x));
+                continue;
+            }
+            if bytes[x] != b'.' {
+                row.symbols.push((x, bytes[x]));
+            }
+            x += 1;
+        }
+        Ok(row)
+    }
+
+    // the numbers touching column x, diagonals included
+    fn numbers_around(&self, x: usize) -> impl Iterator<Item = u32> + '_ {
+        self.numbers.iter().filter(move |(_, r)| r.start <= x + 1 && x <= r.end).map(|&(v, _)| v)
+    }
+}
+
+#[derive(Default)]
+pub struct Analyzer {
+    above: Row,
+    // None before the first line
+    current: Option<Row>,
+    // number of the current row
+    row: usize,
+}
+
+impl Analyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, l: &str) -> Result<Vec<Event>, ParseError> {
+        let below = Row::parse(l)?;
+        let events = match self.current.take() {
+            Some(current) => {
+                let events = self.finalize(&current, &below);
+                self.above = current;
+                events
+            }
+            None => Vec::new(),
+        };
+        self.current = Some(below);
+        self.row += 1;
+        Ok(events)
+    }
+
+    // The last row, with nothing below. The analyzer is then empty again.
+    pub fn finish(&mut self) -> Vec<Event> {
+        let events = match self.current.take() {
+            Some(current) => self.finalize(&current, &Row::default()),
+            None => Vec::new(),
+        };
+        *self = Self::new();
+        events
+    }
+
+    fn finalize(&self, current: &Row, below: &Row) -> Vec<Event> {
+        let rows = [&self.above, current, below];
+        let mut events = Vec::new();
+        for (value, r) in &current.numbers {
+            let touches = |row: &Row| row.symbols.iter().any(|&(x, _)| r.start <= x + 1 && x <= r.end);
+            if rows.iter().any(|row| touches(row)) {
+                events.push(Event::Part { row: self.row, value: *value });
+            }
+        }
+        for &(x, _) in current.symbols.iter().filter(|&&(_, s)| s == b'*') {
+            let around: Vec<u32> = rows.iter().flat_map(|row| row.numbers_around(x)).collect();
+            if let [a, b] = around[..] {
+                events.push(Event::Gear { row: self.row, x, ratio: a as u64 * b as u64 });
+            }
+        }
+        events
+    }
+}
+
+// Both totals from the events, the answer being the one of the part
+struct Solver {
+    analyzer: Analyzer,
+    part_2: bool,
+    parts: u64,
+    gears: u64,
+}
+
+impl Solver {
+    fn new(part_2: bool) -> Self {
+        Self{analyzer : Analyzer::new(),
+             part_2,
+             parts : 0,
+             gears : 0,
+        }
+    }
+
+    fn add(&mut self, events: Vec<Event>) {
+        for e in events {
+            debug!("{:?}", e);
+            match e {
+                Event::Part { value, .. } => self.parts += value as u64,
+                Event::Gear { ratio, .. } => self.gears += ratio,
+            }
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        let events = self.analyzer.push(l)?;
+        self.add(events);
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        let events = self.analyzer.finish();
+        self.add(events);
+        info!("part numbers {}, gear ratios {}", self.parts, self.gears);
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        if self.part_2 { self.gears.into() } else { self.parts.into() }
+    }
+}
+
+pub fn run(reader: &mut dyn BufRead, part_2: bool) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(part_2), reader)
+}
+
+// The switch of both parts
+pub const PARAMS: &[aoc_common::Param] = &[aoc_common::Param {
+    name: "stream",
+    help: "solve both parts in one pass keeping only three rows",
+    min: 0,
+    max: 1,
+}];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // All the events of the schematic, with the row pushed when emitted
+    fn events(schematic: &[&str]) -> Vec<(usize, Event)> {
+        let mut analyzer = Analyzer::new();
+        let mut all = Vec::new();
+        for (n, l) in schematic.iter().enumerate() {
+            all.extend(analyzer.push(l).unwrap().into_iter().map(|e| (n + 1, e)));
+        }
+        all.extend(analyzer.finish().into_iter().map(|e| (0, e)));
+        all
+    }
+
+    #[test]
+    fn gears() {
+        assert_eq!(events(&["1.2", ".*.", "..."]), vec![
+            (2, Event::Part { row: 1, value: 1 }),
+            (2, Event::Part { row: 1, value: 2 }),
+            (3, Event::Gear { row: 2, x: 1, ratio: 2 }),
+        ]);
+        // a third number below: not a gear
+        assert_eq!(events(&["1.2", ".*.", "3.."]), vec![
+            (2, Event::Part { row: 1, value: 1 }),
+            (2, Event::Part { row: 1, value: 2 }),
+            (0, Event::Part { row: 3, value: 3 }),
+        ]);
+        // a gear is a '*' only
+        assert_eq!(events(&["4#5"]), vec![
+            (0, Event::Part { row: 1, value: 4 }),
+            (0, Event::Part { row: 1, value: 5 }),
+        ]);
+    }
+
+    #[test]
+    fn row_edges_and_diagonals() {
+        let schematic = [
+            "12....34",
+            "..+..$..",
+            "7......8",
+            ".......*",
+            "56....9.",
+        ];
+        let parts: Vec<u32> = events(&schematic).into_iter()
+            .filter_map(|(_, e)| match e {
+                Event::Part { value, .. } => Some(value),
+                Event::Gear { .. } => None,
+            })
+            .collect();
+        // 12 and 34 on the diagonals of + and $, 7 and 56 touching nothing
+        assert_eq!(parts, vec![12, 34, 8, 9]);
+        assert!(events(&schematic).contains(&(5, Event::Gear { row: 4, x: 7, ratio: 72 })));
+    }
+
+    #[test]
+    fn last_row_on_finish() {
+        let mut analyzer = Analyzer::new();
+        assert_eq!(analyzer.push("...").unwrap(), vec![]);
+        assert_eq!(analyzer.push(".5*").unwrap(), vec![]);
+        assert_eq!(analyzer.finish(), vec![Event::Part { row: 2, value: 5 }]);
+        // empty again
+        assert_eq!(analyzer.finish(), vec![]);
+        assert_eq!(analyzer.push("*3").unwrap(), vec![]);
+        assert_eq!(analyzer.finish(), vec![Event::Part { row: 1, value: 3 }]);
+    }
+
+    #[test]
+    fn empty_schematic() {
+        assert_eq!(events(&[]), vec![]);
+        assert_eq!(events(&["", ""]), vec![]);
+    }
+}
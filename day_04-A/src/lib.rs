@@ -0,0 +1,65 @@
+/*
+https://adventofcode.com/2023/day/4
+
+ */
+
+
+use std::io::{self, BufRead};
+use std::collections::HashSet;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::debug;
+use aoc_common::Answer;
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i32,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        // Ignore card number
+        let (_, numbers) = parse::record::<u32>(l, "Card")?;
+        let (winning, yours) = parse::pair(l, numbers, "|", "<winning numbers> | <your numbers>")?;
+        // parse::numbers() treats consecutive spaces as only one separator
+        // (input example pads single-digit numbers with spaces for formatting)
+        let winning: HashSet<u32> = parse::numbers(l, winning)?.into_iter().collect();
+        let yours: HashSet<u32> = parse::numbers(l, yours)?.into_iter().collect();
+
+        debug!("numbers: {:?} and {:?}", winning, yours);
+
+        // Get size of Intersection
+        let sames = winning.intersection(&yours).count();
+        // For scoring, if > 0 we use powers of 2.
+        if sames > 0 {
+            debug!("{} winning numbers", sames);
+            self.total += 1 << (sames - 1);
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,96 @@
+/*
+https://adventofcode.com/2023/day/4
+
+ */
+
+
+use std::io::{self, BufRead};
+use std::collections::HashSet;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::Answer;
+
+// Solver for this particular problem
+
+struct Solver {
+    total: usize,
+    current_line: usize,
+    duplicates: Vec<usize>,  /* number of duplicates of card [n] (including the original one).
+                              * card 1 starts at [1] , [0] is ignored
+                              */
+}
+
+
+// vec[index] += value
+// grows vec as needed to include [index] and initialize it by 0
+fn add_value_to_index(vec: &mut Vec<usize>, value: usize, index: usize)  {
+    if vec.len() <= index {
+        vec.resize(index+1, 0);
+    }
+    vec[index] += value;
+}
+
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+             current_line:0,
+             duplicates: Vec::<usize>::new(),
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        // Ignore card number
+        let (_, numbers) = parse::record::<u32>(l, "Card")?;
+        let (winning, yours) = parse::pair(l, numbers, "|", "<winning numbers> | <your numbers>")?;
+
+        // We could parse it from line[0] but it's just simpler like that
+        self.current_line += 1;
+        // count this original scratch-card
+        add_value_to_index(&mut self.duplicates, 1, self.current_line);
+        
+        // parse::numbers() treats consecutive spaces as only one separator
+        // (input example pads single-digit numbers with spaces for formatting)
+        let winning: HashSet<u32> = parse::numbers(l, winning)?.into_iter().collect();
+        let yours: HashSet<u32> = parse::numbers(l, yours)?.into_iter().collect();
+
+        // Get size of Intersection
+        let sames = winning.intersection(&yours).count();
+        // duplicate following cards by the count of matched numbers
+        if sames > 0 {
+            // We may have been duplicated by previous cards,
+            // duplicate next cards accordingly
+            let factor = self.duplicates[self.current_line];
+            for k in (self.current_line+1)..(self.current_line+1+sames) {
+                add_value_to_index(&mut self.duplicates, factor, k);
+                // Note: by the puzzle description, it is guaranteed that the last
+                // cards will not "win" and thus have no risk to duplicate
+                // cards number above the real parsed cards, so will not
+                // count "virtual" cards in the final total.
+                // If not, we would have needed to shrink the vector at self.current_line.
+            }
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        // count all the cards
+        self.total = self.duplicates.iter().sum();
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
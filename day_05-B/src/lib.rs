@@ -0,0 +1,170 @@
+/*
+https://adventofcode.com/2023/day/5
+--- Day 5: If You Give A Seed A Fertilizer ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_algo::{IntervalSet, PiecewiseMap};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::debug;
+use aoc_common::Answer;
+
+
+// Problem B adds ranges to the seeds.
+// We will collapse all the successive mappings into only 1 (seed->location).
+// For this, each individual mapping will be split into multiple ones when their
+// destination range cover different source ranges in the next mapping.
+// Its size will be, at maximum, the sum of all number of mappings at each level
+// (including implicit ones between to explicit mapping ranges)
+// to get the list of contiguous ranges of starting "seed" ranges (mapping to
+// the same "location") then find which one maps to the lowest location number.
+
+
+// A single source-dest map from the almanac.
+// HashMaps are not possible for this problem (millions of individual k-v) so it uses
+// the ranges of the mapping, each moving its source values by the same delta.
+// i64 required, i32 is too small for the puzzle data.
+struct GardenMap {
+    map: PiecewiseMap,
+}
+
+impl GardenMap {
+    fn new() -> Self {
+        Self{ map: PiecewiseMap::new(), }
+    }
+    fn is_empty(&self) -> bool {
+        self.map.pieces().is_empty()
+    }
+
+    fn add_mapping_element(&mut self, destination: i64, source: i64, length: i64) {
+        // The map list may be unordered, but not overlapping.
+        self.map.insert(source..source+length, destination-source);
+    }
+
+    // Collapse two mappings into one, may split at limits.
+    fn get_collapsed_mapping(&self, next: &GardenMap) -> GardenMap {
+        GardenMap{ map: self.map.then(&next.map) }
+    }
+}
+
+
+
+struct Almanac {
+    all_maps: Vec<GardenMap>, // All successive maps from the almanac.
+}
+
+
+impl Almanac {
+    fn new() -> Self {
+        Self{ all_maps: Vec::<GardenMap>::new(), }
+    }
+
+
+    fn add_mapping(&mut self, m: GardenMap) {
+        self.all_maps.push(m);
+    }
+
+    // Collapse all the maps from the first to the last into a
+    // single seed -> location one.
+    fn get_collapsed_mapping(&self) -> GardenMap {
+        self.all_maps.iter().fold(GardenMap::new(), |collapsed, m| collapsed.get_collapsed_mapping(m))
+    }
+}
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i64,
+    seeds: Vec<i64>,
+    is_parsing_maps: bool, // context for the line-by-line parser
+    almanac : Almanac,
+    current_map: GardenMap,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+             seeds: Vec::<i64>::new(),
+             is_parsing_maps : false,
+             almanac : Almanac::new(),
+             current_map : GardenMap::new(),
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        if l.is_empty() {
+            return Ok(());
+        }
+
+        if self.is_parsing_maps {
+            // Parsing the different maps.
+
+            // Detect a new "map header" ?
+            if l.chars().next().unwrap().is_ascii_alphabetic() {
+                if !self.current_map.is_empty() {
+                    // new header: store old one and change map for future work
+                    // (atomically replace our local borrow by a new allocated one)
+                    self.almanac.add_mapping(std::mem::replace(&mut self.current_map,
+                                                               GardenMap::new())
+                    );
+                }
+                // else: it was just the first header, we already have a map from our new()
+                return Ok(()); // header processed, mapping syntax will be found on next call of process()
+            } // else: keep current map
+
+            // should be a 3-number line.
+            let mapping: Vec<i64> = l.split_whitespace()
+                .map(|s| parse::number(l, s))
+                .collect::<Result<_, _>>()?;
+            if mapping.len() != 3 {
+                return Err(ParseError::new(1, "a mapping of 3 numbers", l));
+            }
+            self.current_map.add_mapping_element(mapping[0], mapping[1], mapping[2]);
+
+        } else {
+            // parsing the initial seed list
+            let Some((_, seed_list)) = l.split_once(':') else {
+                return Err(ParseError::new(1, "seeds: <numbers>", l));
+            };
+            self.seeds = seed_list.split_whitespace()
+                .map(|s| parse::number(l, s))
+                .collect::<Result<_, _>>()?;
+            if !self.seeds.len().is_multiple_of(2) {
+                return Err(ParseError::at_end(l, "a length after the last seed start"));
+            }
+            self.is_parsing_maps = true;
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        // Store/flush the last mapping being parsed now that we reached end-of-file.
+        self.almanac.add_mapping(std::mem::replace(&mut self.current_map,
+                                                   GardenMap::new()));
+        let seed_to_location = self.almanac.get_collapsed_mapping();
+        debug!("Seed to location collapsed into {} ranges", seed_to_location.map.pieces().len());
+
+        // The seeds are pairs of (start, length): the lowest location is the start
+        // of one of the ranges of their image.
+        let seeds: IntervalSet<i64> = self.seeds.chunks(2).map(|p| p[0]..p[0]+p[1]).collect();
+        self.total = seed_to_location.map.apply_set(&seeds).min().unwrap();
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,17 @@
+/*
+https://adventofcode.com/2023/day/6
+--- Day 6: Wait For It ---
+ */
+
+pub mod part_a;
+pub mod part_b;
+
+use aoc_common::ParseError;
+
+// The values of the n-th line of input, after its "Time:" or "Distance:" header
+fn header_values<'a>(line: &'a str, n: usize, header: &str) -> Result<&'a str, ParseError> {
+    match line.split_once(':') {
+        Some((h, values)) if h == header => Ok(values),
+        _ => Err(ParseError::new(1, format!("{header}: <numbers>"), line).with_line(n)),
+    }
+}
@@ -0,0 +1,105 @@
+/*
+https://adventofcode.com/2023/day/6
+--- Day 6: Wait For It ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse;
+use aoc_common::debug;
+use aoc_common::Answer;
+
+
+// "solver" pattern not really interesting for this problem
+// with only 2 lines to parse with their own specific meanings:
+// everything is done in process_all().
+
+
+// For a Max time T, the race is divided by button-press time 'P' in [0..T]
+// and Moving time M = T-P.
+// Speed V is equal to P, so distance covered = M * V = (T-P) * P = TP - P^2
+// and we want it to be superior to the record D (distance) so
+// -P^2 + TP - D > 0
+// and P an integer.
+fn compute_number_of_ways_winning(time: i32, distance: i32) -> i32 {
+    let a: f32 = -1.0;
+    let b: f32 = time as f32;
+    // case where the previous distance was also an integer requires to be
+    // strictly better. Simpler is to add a small epsilon to force new
+    // roots to be inside and pray.
+    let c: f32 = -0.01 + (-distance) as f32 ;
+    let delta = b*b-4.0*a*c;
+    if delta < 0.0 {
+        return  0;
+    }
+    // with our coefficients, root1 < root2
+    let root1 = (-b + delta.sqrt()) / (2.0 * a);
+    let root2 = (-b - delta.sqrt()) / (2.0 * a);
+
+    debug!("float {root1} and {root2}");
+
+    // for counting the integral number of winning cases,
+    // get the first integers inside the roots range,
+    // so above root1 and below root2
+    let i_root1:i32 = root1.ceil() as i32;
+    let i_root2:i32 = root2.trunc() as i32;
+
+
+    let total = i_root2 - i_root1 + 1;
+    debug!("{}/{} : {} winning moves between {} and {}",
+              time, distance, total, i_root1, i_root2);
+    total
+}
+
+struct Solver {
+    total: i32,
+}
+
+impl aoc_common::Solver for Solver {
+    // process the 2 lines of input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let mut lines = aoc_common::lines(reader);
+        let time_line = lines.next().transpose()?.unwrap_or_default();
+        let distance_line = lines.next().transpose()?.unwrap_or_default();
+        // drop the "header" and keep the values
+        let input_time = super::header_values(&time_line, 1, "Time")?;
+        let input_distance = super::header_values(&distance_line, 2, "Distance")?;
+
+        // parallel iterates between the two lists to create a vector
+        // of data pairs
+        let times_it = input_time.split_whitespace();
+        let mut distances_it = input_distance.split_whitespace();
+        let mut races = Vec::<(i32,i32)>::new();
+        for t in times_it {
+            // It would be an input error if both didn't
+            // have the same size
+            let d = parse::next(&distance_line, &mut distances_it, "a distance for each time")
+                .map_err(|e| e.with_line(2))?;
+            races.push((parse::number(&time_line, t).map_err(|e| e.with_line(1))?,
+                        parse::number(&distance_line, d).map_err(|e| e.with_line(2))?));
+        }
+        debug!("Races = {:?}", races);
+
+        let total_number_of_ways: i32 = races
+            .iter()
+            .map(|race| compute_number_of_ways_winning(race.0, race.1))
+            .product();
+
+        self.total = total_number_of_ways;
+        Ok(())
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver { total: 0 }, reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,47 @@
+/*
+https://adventofcode.com/2023/day/8
+--- Day 8: Haunted Wasteland ---
+ */
+
+pub mod part_a;
+pub mod part_b;
+
+use aoc_common::ParseError;
+
+// The L/R walk instructions of the first line
+fn parse_walk(l: &str) -> Result<String, ParseError> {
+    if let Some((k, c)) = l.char_indices().find(|&(_, c)| c != 'L' && c != 'R') {
+        return Err(ParseError::at(l, &l[k..k + c.len_utf8()], "L or R"));
+    }
+    if l.is_empty() {
+        return Err(ParseError::at_end(l, "the L/R instructions"));
+    }
+    Ok(l.to_string())
+}
+
+// "AAA = (BBB, CCC)" => ("AAA", ("BBB", "CCC"))
+fn parse_node(l: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let Some((name, rest)) = l.split_once(" = ") else {
+        return Err(ParseError::new(1, "<node> = (<left>, <right>)", l));
+    };
+    let Some(pair) = rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) else {
+        return Err(ParseError::at(l, rest, "(<left>, <right>)"));
+    };
+    let Some((left, right)) = pair.split_once(", ") else {
+        return Err(ParseError::at(l, pair, "<left>, <right>"));
+    };
+    Ok((name, (left, right)))
+}
+
+// Error on the first left or right node of the node lines which is not
+// in the network. A node may be used before the line defining it, so
+// this is only known once all the lines are read.
+fn check_network(node_lines: &[(usize, String)], is_node: impl Fn(&str) -> bool) -> Result<(), ParseError> {
+    for (n, l) in node_lines {
+        let (_, (left, right)) = parse_node(l)?;
+        if let Some(dest) = [left, right].into_iter().find(|d| !is_node(d)) {
+            return Err(ParseError::at(l, dest, "a node of the network").with_line(*n));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+/*
+https://adventofcode.com/2023/day/8
+--- Day 8: Haunted Wasteland ---
+ */
+
+
+use std::io::{self, BufRead};
+use std::collections::HashMap;
+use aoc_common::ParseError;
+use aoc_common::Answer;
+
+type Node = (String, String);
+
+// Solver for this particular problem
+
+struct Solver {
+    walk: String,
+    total: i32,
+    network: HashMap<String,Node>,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{walk:String::new(),
+             total : 0,
+             network: HashMap::<String,Node>::new(),
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        // the L/R walk instructions are on the first line,
+        // followed by an empty line
+        if self.walk.is_empty() {
+            self.walk = super::parse_walk(l)?;
+            return Ok(());
+        }
+        if l.is_empty() {
+            return Ok(());
+        }
+        let (node_name, (node_l, node_r)) = super::parse_node(l)?;
+        self.network.insert(node_name.to_string(), (node_l.to_string(), node_r.to_string()));
+        Ok(())
+    }
+
+    // The network is checked once complete, so the walk cannot get lost
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+        // the node lines with their number, after the walk on the first one
+        let mut node_lines = Vec::new();
+        for (n, l) in aoc_common::lines(reader).enumerate() {
+            let l = l?;
+            self.process(&l).map_err(|e| e.with_line(n + 1))?;
+            if n > 0 && !l.is_empty() {
+                node_lines.push((n + 1, l));
+            }
+        }
+        super::check_network(&node_lines, |d| self.network.contains_key(d))?;
+        if let Some(missing) = ["AAA", "ZZZ"].into_iter().find(|n| !self.network.contains_key(*n)) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                      format!("the network has no {missing} node")));
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        let mut step = 0;
+        let end = String::from("ZZZ");
+        let start = String::from("AAA");
+        let mut label = &start;
+        let mut direction = self.walk.chars();
+        loop {
+            let node = &self.network[label];
+            
+            match direction.next() {
+                Some('L') => label = &node.0,
+                Some('R') => label = &node.1,
+                Some(_) => panic!("Invalid left/right instruction"),
+                None => { // End of L/R instruction: wrap around
+                    direction = self.walk.chars();
+                    continue;
+                },
+            }
+            step += 1;
+            if label == &end {
+                break;
+            }
+            
+        }
+        self.total = step;
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
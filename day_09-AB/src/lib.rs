@@ -0,0 +1,145 @@
+/*
+https://adventofcode.com/2023/day/9
+--- Day 9: Mirage Maintenance ---
+ */
+
+
+/*
+ * This program solves part 1 and part 2 simultaneously.
+ * For once, the "part 2 plot twist" could fit perfectly
+ * in the algo of part 1 with just a few more lines.
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::debug;
+use aoc_common::Answer;
+
+
+
+struct ValueSequence {
+    v: Vec<i32> ,
+}
+
+impl ValueSequence {
+    fn from_vec(v: Vec<i32>) -> Self {
+        Self {
+            v,
+        }
+    }
+
+    // Return a new valueSequence whose content
+    // is the discrete derivation of self (adjacent difference)
+    fn derive(&self) -> Self {
+        // Use slice windows() to operate on a sliding
+        // window of 2 elements to get their difference.
+        let slice = &self.v[..];
+        let deriv = slice.windows(2).map(|w| w[1]-w[0]).collect();
+        ValueSequence::from_vec(deriv)
+    }
+
+    // Add a new elements to self using the last or first element of d
+    fn grow_by_integration(&mut self, d: &ValueSequence) {
+        self.v.insert(0, self.v[0] - d.v[0]);
+        self.v.push(self.v.last().unwrap() + d.v.last().unwrap());
+    }
+
+    fn is_all_zero(&self) -> bool {
+        for k in &self.v {
+            if *k != 0 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total_next: i32,
+    total_previous: i32,
+    part_2: bool,
+}
+
+impl Solver {
+    fn new(part_2: bool) -> Self {
+        Self{
+            total_next : 0,
+            total_previous : 0,
+            part_2,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        let mut pyramid = Vec::<ValueSequence>::new();
+
+        // The history of this line's value
+        let v = ValueSequence::from_vec(l.split_whitespace()
+                                        .map(|s| parse::number(l, s))
+                                        .collect::<Result<_, _>>()?
+        );
+        pyramid.push(v);
+        // compute the N derivation until reaching all zeros
+        while !pyramid.last().unwrap().is_all_zero() {
+            let deriv = pyramid.last().unwrap().derive();
+            pyramid.push(deriv);
+        }
+
+        let derive_len = pyramid.len(); // for debug
+        // Now do the reverse by growing each sequence from last to first.
+        let mut deriv = pyramid.pop().unwrap();
+        // Technically we should add a final "0" but all the other existing 
+        // zeroes will work the same for this one.
+
+        let mut last_value:i32 = 0;
+        let mut first_value:i32 = 0;
+        while let Some(mut current) = pyramid.pop() {
+            current.grow_by_integration(&deriv);
+            last_value = *current.v.last().unwrap();
+            first_value = current.v[0];
+            deriv = current;
+        }
+
+        debug!("Sequence {l}: ∂/∂t ^ {derive_len} => {first_value}..{last_value}");
+
+        self.total_next += last_value; 
+        self.total_previous += first_value;
+        Ok(())
+   }
+
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the final string of expected output: both parts are computed
+    // by the same pass, only the selected one is returned.
+    fn result(&self) -> Answer {
+        if self.part_2 {
+            self.total_previous.into()
+        } else {
+            self.total_next.into()
+        }
+    }
+}
+
+// only the part to solve, given with --part
+pub const PARAMS: &[aoc_common::Param] = &[];
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(params.part_2()), reader)
+}
+
+pub fn solve_a(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
+
+pub fn solve_b(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new_part_2(PARAMS))
+}
@@ -0,0 +1,147 @@
+/*
+https://adventofcode.com/2023/day/10
+--- Day 10: Pipe Maze ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_grid::{Direction4, Grid};
+use aoc_common::{debug, info, trace};
+use aoc_common::Answer;
+
+
+use Direction4::*;
+
+// This puzzle tiles can be described by the two possible exit
+// directions relative to our selves
+struct Connection {
+    d: Option<[Direction4;2]>, // order of d[0] and d[1] is not important
+}
+
+
+impl Connection {
+    fn from_char(c: char) -> Option<Connection> {
+        let d = match c {
+            '.' => None, // special case, no direction at all
+            'S' => None, // until we know better
+            '-' => Some([Left, Right]),
+            '|' => Some([Up, Down]),
+            'L' => Some([Up, Right]),
+            'J' => Some([Up, Left]),
+            '7' => Some([Left, Down]),
+            'F' => Some([Right, Down]),
+            _ => return None, // not a valid tile
+        };
+        Some(Connection{ d })
+    }
+
+    // If the tile in direction (from) is connected to us, where does the opposing connection
+    // leads to ?
+    // For example if coming from the tile East of us (Right) and we are a "L" ,
+    // this will return Up
+    //   ___|  next|__
+    //   ___[ self ] from Right
+    //      |      |
+    // Return None when this title is not possibly connected.
+    fn next_coordinate_coming_from(&self, from: Direction4) -> Option<Direction4> {
+        let d = self.d?;
+        // find the matching entry and return the opposit exit
+        for i in 0..=1 {
+            if from == d[i] {
+                return Some(d[1-i]);
+            }
+        }
+        None
+    }
+}
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i32,
+    s_coordinate: (usize,usize),
+    map: Grid<Connection>,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+             s_coordinate: (0,0),
+             map: Grid::from_rows(Vec::new()),
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+        let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        let tiles = Grid::parse(&lines, "a tile", |c| Connection::from_char(c).map(|_| c))?;
+        if let Some(s) = tiles.find(|&c| c == 'S') {
+            self.s_coordinate = s;
+            debug!("S is at {:?}", self.s_coordinate);
+        }
+        self.map = tiles.map(|&c| Connection::from_char(c).expect("tiles checked when parsing"));
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        let mut loop_follow:(usize,usize) = (0, 0);
+        let mut loop_from = Right; // until found
+        let mut loop_found = false;
+
+        for d in Direction4::ALL {
+            // S may be on a side of the map
+            let Some(t) = self.map.step_towards(self.s_coordinate.0, self.s_coordinate.1, d) else {
+                continue;
+            };
+            let adj_tile = &self.map[t];
+            // reverse direction
+            match adj_tile.next_coordinate_coming_from(d.opposite()) {
+                None => continue, // not connectable tile
+                Some(n) => {
+                    loop_follow = t;
+                    debug!("Found the loop start towards {:?} then {:?}, or {:?}", d, n, loop_follow);
+                    loop_from = d.opposite();
+                    loop_found = true;
+                    break;
+                },
+            }
+        }
+        if !loop_found {
+            panic!("No starting loop found");
+        }
+        // Follow the loop until it reaches back to S
+        let mut loop_length = 2; // S and our starting connected tile
+        while loop_follow != self.s_coordinate {
+            let tile = &self.map[loop_follow];
+            match tile.next_coordinate_coming_from(loop_from) {
+                None => panic!("Loop was broken"),
+                Some(n) => {
+                    loop_follow = self.map.step_towards(loop_follow.0, loop_follow.1, n).expect("Loop leaves the map");
+                    loop_from = n.opposite();
+                    trace!("walking to {},{}", loop_follow.0, loop_follow.1);
+                    loop_length += 1;
+                },
+            }
+        }
+        loop_length -= 1; // We counted S twice when coming back to it
+        info!("Looped back with length {loop_length}");
+        self.total = loop_length / 2;
+        
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
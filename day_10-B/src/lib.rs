@@ -0,0 +1,221 @@
+/*
+https://adventofcode.com/2023/day/10
+--- Day 10: Pipe Maze ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_grid::{Direction4, Grid, Location, Point2, Polygon};
+use std::fmt;
+use aoc_common::log::Level;
+use aoc_common::{debug, info, log_enabled};
+use aoc_common::Answer;
+
+use Direction4::*;
+
+// This puzzle tiles can be described by the two possible exit
+// directions relative to our selves
+struct Connection {
+    d: Option<[Direction4;2]>, // order of d[0] and d[1] is not important
+}
+
+
+impl Connection {
+    fn from_char(c: char) -> Option<Connection> {
+        let d = match c {
+            '.' => None, // special case, no direction at all
+            'S' => None, // until we know better
+            '-' => Some([Left, Right]),
+            '|' => Some([Up, Down]),
+            'L' => Some([Up, Right]),
+            'J' => Some([Up, Left]),
+            '7' => Some([Left, Down]),
+            'F' => Some([Right, Down]),
+            _ => return None, // not a valid tile
+        };
+        Some(Connection{ d })
+    }
+
+    // If the tile in direction (from) is connected to us, where does the opposing connection
+    // leads to ?
+    // For example if coming from the tile East of us (Right) and we are a "L" ,
+    // this will return Up
+    //   ___|  next|__
+    //   ___[ self ] from Right
+    //      |      |
+    // Return None when this title is not possibly connected.
+    fn next_coordinate_coming_from(&self, from: Direction4) -> Option<Direction4> {
+        let d = self.d?;
+        // find the matching entry and return the opposit exit
+        for i in 0..=1 {
+            if from == d[i] {
+                return Some(d[1-i]);
+            }
+        }
+        None
+    }
+}
+
+// Solver for this particular problem
+// The tiles of the loop, only for the debug print
+#[derive(Clone)]
+enum LoopHint {
+    None,
+    Segment(bool, bool), // reaches (up, down) (else corner or horizontal)
+    Interior,
+}
+
+impl LoopHint {
+    fn from_connection(c: &Connection) -> Self {
+        // No point in our case to return "None" when connection is ground
+        let up = c.d.is_some_and(|d| d.contains(&Up)); // any connection up ?
+        let down = c.d.is_some_and(|d| d.contains(&Down)); // any down ?
+        LoopHint::Segment(up,down)
+    }
+    fn to_char(&self) -> char {
+        match self {
+            LoopHint::None => '.',
+            LoopHint::Interior => 'O',
+            LoopHint::Segment(false,false) => '-',
+            LoopHint::Segment(true,false) => '^',
+            LoopHint::Segment(false,true) => 'v',
+            LoopHint::Segment(true,true) => '|',
+        }
+    }
+}
+
+impl fmt::Debug for LoopHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoopHint::None =>   write!(f, "."),
+            LoopHint::Interior => write!(f, "O"),
+            LoopHint::Segment(false,false) => write!(f, "-"),
+            LoopHint::Segment(true,false) => write!(f, "^"),
+            LoopHint::Segment(false,true) => write!(f, "v"),
+            LoopHint::Segment(true,true) => write!(f, "|"),
+        }
+    }
+}
+
+
+struct Solver {
+    total: i32,
+    s_coordinate: (usize,usize),
+    map: Grid<Connection>,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+             s_coordinate: (0,0),
+             map: Grid::from_rows(Vec::new()),
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+        let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        let tiles = Grid::parse(&lines, "a tile", |c| Connection::from_char(c).map(|_| c))?;
+        if let Some(s) = tiles.find(|&c| c == 'S') {
+            self.s_coordinate = s;
+            debug!("S is at {:?}", self.s_coordinate);
+        }
+        self.map = tiles.map(|&c| Connection::from_char(c).expect("tiles checked when parsing"));
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        let mut loop_follow:(usize,usize) = (0, 0);
+        let mut loop_from = Right; // until found
+        let mut loop_found = false;
+        let mut s_up = false; // needed only for the debug print
+        let mut s_down = false;
+
+        for d in Direction4::ALL {
+            // S may be on a side of the map
+            let Some(t) = self.map.step_towards(self.s_coordinate.0, self.s_coordinate.1, d) else {
+                continue;
+            };
+            let adj_tile = &self.map[t];
+            // reverse direction
+            match adj_tile.next_coordinate_coming_from(d.opposite()) {
+                None => continue, // not connectable tile
+                Some(n) => {
+                    loop_follow = t;
+                    debug!("Found the loop start towards {:?} then {:?}, or {:?}", d, n, loop_follow);
+                    loop_from = d.opposite();
+                    loop_found = true;
+                    //break; We don't break because we need to get all connections
+                    // of Start for the debug print
+                    if d == Down {
+                        s_down = true;
+                    }
+                    if d == Up {
+                        s_up = true;
+                    }
+                },
+            }
+        }
+        if !loop_found {
+            panic!("No starting loop found");
+        }
+
+        // The loop is a polygon joining the centers of its tiles, and the
+        // tiles enclosed are the lattice points strictly inside it: Pick's
+        // theorem counts them from the area (Shoelace formula) and the
+        // number of tiles of the loop, without looking at the map again.
+        // No need to know the exact value of S, only where the loop goes.
+        let position = |(x, y): (usize, usize)| Point2::new(x as i64, y as i64);
+        let mut vertices = vec![position(self.s_coordinate)];
+
+        let mut loop_map = Grid::new(self.map.width(), self.map.height(), LoopHint::None);
+
+        // only if S is connected to top and/or bottom, for the debug print
+        loop_map[self.s_coordinate] = LoopHint::Segment(s_up, s_down);
+
+        // Follow the loop until it reaches back to S
+        while loop_follow != self.s_coordinate {
+            let tile = &self.map[loop_follow];
+            vertices.push(position(loop_follow));
+            loop_map[loop_follow] = LoopHint::from_connection(tile);
+            match tile.next_coordinate_coming_from(loop_from) {
+                None => panic!("Loop was broken"),
+                Some(n) => {
+                    loop_follow = self.map.step_towards(loop_follow.0, loop_follow.1, n).expect("Loop leaves the map");
+                    loop_from = n.opposite();
+                },
+            }
+        }
+
+        let pipe_loop = Polygon::new(vertices);
+        info!("Loop of {} tiles, area {}", pipe_loop.boundary_points(), pipe_loop.double_area() / 2);
+        self.total = pipe_loop.interior_points() as i32;
+
+        if log_enabled!(Level::Debug) {
+            for ((x, y), hint) in loop_map.iter_mut() {
+                if matches!(hint, LoopHint::None) && pipe_loop.locate(position((x, y))) == Location::Inside {
+                    *hint = LoopHint::Interior;
+                }
+            }
+            for k in loop_map.rows() {
+                debug!("{}", k.iter().map(LoopHint::to_char).collect::<String>());
+            }
+        }
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
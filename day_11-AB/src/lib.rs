@@ -0,0 +1,150 @@
+/*
+https://adventofcode.com/2023/day/11
+--- Day 11: Cosmic Expansion ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::ParseError;
+use aoc_common::{debug, trace};
+use aoc_common::Answer;
+
+
+// Solver for this particular problem
+
+struct Solver {
+    expansion_factor: i64,
+    total: i64,
+    galaxies: Vec<(i64,i64)>,  // coordinates (x,y)
+    current_y : i64,
+    max_x: i64,
+    max_y: i64,
+}
+
+impl Solver {
+    fn new(factor: i64) -> Self {
+        Self{expansion_factor : factor,
+             total : 0,
+             galaxies : Vec::<(i64,i64)>::new(),
+             current_y : 0,
+             max_x : 0,
+             max_y : 0,
+        }
+    }
+
+    fn integral_distance(g1: &(i64,i64), g2: &(i64,i64)) -> i64 {
+        // The "shortest distance using only up/down/left/right"
+        // is simply the Manhattan distance (giving "Diamond" circles topology)
+        (g1.0 - g2.0).abs() + (g1.1 - g2.1).abs()
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        for (x, c) in (0..).zip(l.chars()) {
+            if c == '#' {
+                self.galaxies.push((x, self.current_y));
+                if x > self.max_x {
+                    self.max_x = x;
+                }
+                if self.current_y > self.max_y {
+                    self.max_y = self.current_y;
+                }
+            } else if c != '.' {
+                return Err(ParseError::new(x as usize + 1, "'.' or '#'", c));
+            }
+        }
+        self.current_y += 1;
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        // look for X and Y coordinates without any galaxy.
+        let mut empty_x: Vec<bool> = vec![true; 1 + self.max_x as usize];
+        let mut empty_y: Vec<bool> = vec![true; 1 + self.max_y as usize];
+
+        for (gx,gy) in &self.galaxies {
+            empty_x[*gx as usize] = false;
+            empty_y[*gy as usize] = false;
+        }
+
+        // Add "1" to the X coordinate of all galaxies that are
+        // on the right of an empty space column, for each of those columns.
+        // Map this +1.. +2... +3 of each column in the following vectors
+        // accumulating all expansions:
+        // Use the actual expansion factor instead of just 1
+
+        let mut expansion_x = Vec::<i64>::new();
+        let mut expansion_y = Vec::<i64>::new();
+        expansion_x.push(0);
+        expansion_y.push(0);
+
+        for &empty in &empty_x {
+            let dx = if empty { self.expansion_factor } else { 0 };
+            expansion_x.push(expansion_x.last().unwrap() + dx);
+        }
+
+        for &empty in &empty_y {
+            let dy = if empty { self.expansion_factor } else { 0 };
+            expansion_y.push(expansion_y.last().unwrap() + dy);
+        }
+
+        // now for the galaxies
+        for g in &mut self.galaxies.iter_mut() {
+            let g0 = *g;
+            *g = (g.0 + expansion_x[g.0 as usize],
+                  g.1 + expansion_y[g.1 as usize]);
+            debug!("expanding {:?} to {:?}", g0, g);
+        }
+
+        // Now compute shortest paths.
+        let mut g_it =  self.galaxies.iter();
+        while let Some(g1) = g_it.next() {
+            // Now iterate on the other galaxies after this one,
+            // by continuing from this same iterator position + 1
+            let g_other = g_it.clone();
+            for g2 in g_other {
+                trace!("Computing D({:?}--{:?})", g1, g2);
+                self.total += Self::integral_distance(g1,g2);
+            }
+
+        }
+        
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+pub const PARAMS: &[aoc_common::Param] = &[aoc_common::Param {
+    name: "factor",
+    help: "size of an empty row or column after expansion (default 2, or 1000000 for part 2)",
+    min: 1,
+    max: i64::MAX,
+}];
+
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+
+    // For problem 2, example "100 times larger": --param factor=100
+    let factor = params.get("factor").unwrap_or(if params.part_2() {
+        // For final  problem 2:
+        1_000_000
+    } else {
+        // For problem 1:
+        2
+    });
+    let s = Solver::new(factor - 1);
+
+    aoc_common::run(s, reader)
+}
+
+pub fn solve_a(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
+
+pub fn solve_b(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new_part_2(PARAMS))
+}
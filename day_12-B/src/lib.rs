@@ -0,0 +1,504 @@
+/*
+https://adventofcode.com/2023/day/12
+--- Day 12: Hot Springs ---
+ */
+
+
+// Uses an "optimized brute-forcer".
+// actually converges in human time (a few minutes instead of centuries)
+// but clearly not immediate.
+// To handle the 1000 lines input, simpler to parallelize between cores:
+// for 32 core (33 lines each),
+// # split -l 32 input_A.txt
+// # for k i x*; do day_12-B < $k > output_$k & done
+// # sum all the output_x* files.
+// this will take a few minutes on a 32-cores threadripper (3 or 4 chunks take 4 minutes
+// while the others take 1 minute, and one take 5 minutes)
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::{self, ParseError};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use aoc_algo::memo::{Equivalent, Memo};
+use aoc_common::debug;
+use aoc_common::Answer;
+
+
+// The condition records only use the '.', '#' and '?' springs
+fn check_condition(l: &str, condition: &str) -> Result<(), ParseError> {
+    match condition.char_indices().find(|&(_, c)| !".#?".contains(c)) {
+        Some((k, c)) => Err(ParseError::at(l, &condition[k..k + c.len_utf8()], "'.', '#' or '?'")),
+        None => Ok(()),
+    }
+}
+
+// The key of the prefixes memo: condition_state string, max_offset in that string,
+// and a small crc list of 1/2/3 elements.
+#[derive(PartialEq, Eq)]
+struct PrefixKey {
+    condition_state: String,
+    max_offset: usize,
+    crc: Vec<i64>,
+}
+
+// Same with borrowed fields, to look up the memo without copying the strings
+#[derive(PartialEq, Eq)]
+struct PrefixQuery<'a> {
+    condition_state: &'a str,
+    max_offset: usize,
+    crc: &'a [i64],
+}
+
+impl PrefixKey {
+    fn as_query(&self) -> PrefixQuery<'_> {
+        PrefixQuery{ condition_state: &self.condition_state, max_offset: self.max_offset, crc: &self.crc }
+    }
+}
+
+// The memo finds a key by the hash of its query: a key hashes as its query
+impl Hash for PrefixQuery<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.condition_state, self.max_offset, self.crc).hash(state);
+    }
+}
+
+impl Hash for PrefixKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_query().hash(state);
+    }
+}
+
+impl Equivalent<PrefixKey> for PrefixQuery<'_> {
+    fn equivalent(&self, key: &PrefixKey) -> bool {
+        *self == key.as_query()
+    }
+
+    fn to_key(&self) -> PrefixKey {
+        PrefixKey{ condition_state: self.condition_state.to_string(), max_offset: self.max_offset, crc: self.crc.to_vec() }
+    }
+}
+
+// the prefixes sizes with their number of arrangements, shared by the
+// memo and the callers
+type Prefixes = Rc<HashMap<usize,i64>>;
+type PrefixMemo = Memo<PrefixKey, Prefixes>;
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i64,
+    memo_prefix: PrefixMemo,
+    // to experiment in algo splitting of the "contiguous group of damaged springs".
+    // 3 or 2 seems optimal (may depend on each input line)
+    split_unit: usize,
+}
+
+impl Solver {
+    fn new(split_unit: usize) -> Self {
+        Self{total : 0,
+             memo_prefix: PrefixMemo::new(),
+             split_unit,
+        }
+    }
+
+
+    // Brute-forcing by counting only 1 by 1 is not possible.
+    // Without finding a clever math formula, and keeping a "tree traversal",
+    // need at some point to be able to multiply things instead of adding 1
+    // to gain a factor of speed.
+    // From the input data and manual tries, simply raising the part-1 result
+    // to a 5th power doesn't work because concatenation very often allows
+    // overlaps and new arrangements to be possible across the boundaries of
+    // the part-1 solutions, so this will not do.
+    
+    // One guess is to split the input data into smaller size chunks, compute
+    // the arrangements for each using the trivial method, and be able to
+    // multiply them together.
+    // Trying to be clever on the Condition Record based on its pattern seems difficult.
+    // Anyway, puzzle input data and experiments show that the real combination
+    // explosion happens for pathological records full of "?" where there is
+    // no pattern anyway.
+
+    // So the split will be done instead based on the "crc" (list of contiguous group)
+    // such as (3,2,2,1,1)
+
+    // We want to get sets of splits made of two parts A and B, such as A contains several
+    // possible arrangements, and all B of a set start at the same point. This way
+    // we can recurse on B, and multiply the results of B by the number of A arrangements.
+    // Splitting the crc list only on the first element will never produce identical
+    // B starting points because by definition, each possible unique span of #### will alway
+    // be of different positions. So the minimal split of CRC is to extract the first two
+    // elements.  For example:
+
+    // ????????????...(size M)  3,2,2,1,1
+    // Split into (3,2) + (2,1,1)
+    // We know that the (2,1,1) must cover 2+1+1 "#" and at least 2 "." (to seperate them)
+    // so the "B" part of the condition record is at least 6 chars long, so the A part
+    // is at most M-6 chars.
+    // Use the brute-force method on input and force it to always end with a "."
+    // "???...."x(M-6)   (3,2)
+    // This will output possible arrangements such as:
+    // .....###.##.
+    // ....###..##.  => C1 = 6 arrangement stopping at characters A1 = 12
+    // ...###...##.
+    // etc
+    // ..###.##.
+    // .###..##.     => Cn = 3 arrangements stopping at character An = 9
+    // ###...##.
+    // Collect all possible Stopping A position (12, 9, ..) (with their arrangement count)
+    // Now for each one of them:
+    // cut off the starting An chars of the initial condition record
+    // [...An cut off]
+    // recurse with remaining input data:
+    //                [??#..??..???]  (2,1,1)
+    // get the result number and multiply it by the Cn
+    // some those totals together
+    // return.
+    // recursion stops when CRC is of size 2 or 1, use only the brute-force on the whole input string.
+
+
+    // For a string containing #/./?, return the length of the minimal
+    // prefix substring matching the crc without any "?" and ending with "#.".
+    // If no match, returns None.
+    // "..###..#...??" (3,1) => Some(9)
+    //  123456789
+    // "..###..#??" (3,1) => None
+    // "..###..#" (3,1) => None
+
+    fn get_exact_prefix(condition_state: &str, crc: &[i64]) -> Option<usize> {
+        let mut k:usize = 0;
+        let mut segment_size:i64 = 0;
+        let mut crc_size:i64;
+        let mut in_segment = false;
+        let mut crc_it = crc.iter();
+
+        match crc_it.next() {
+            None => panic!("get_exact_prefix must be called with non-empty crc vector"),
+            Some(s) => crc_size = *s, // expect the first span of # soon
+        }
+
+        //eprintln!("  get exact {condition_state} for {:?}", crc);
+        // walk the string and compare it in parallel to the expected
+        // spans of crc. There may be some magical one-liner with split/zip/whatever
+        // but I don't know. Anyway we have also to handle the final .
+        for c in condition_state.chars() {
+            match c {
+                '?' => return None,
+                '.' => {
+                    k += 1;
+                    if !in_segment {
+                        continue;
+                    }
+                    if segment_size == crc_size {
+                        in_segment = false;
+                        match crc_it.next() {
+                            None => {
+                                //eprintln!("   String {condition_state} matches {:?} for the first {k} chars", crc);
+                                return Some(k); // we matched all of the crc, we're done.
+                            },
+                            Some(s) => crc_size = *s, // expect a new span of # later
+                        }
+                        continue;
+                    } else {
+                        // The finished ## span was the wrong size.
+                        //eprintln!("   wrong span {segment_size}/{crc_size} at {k}");
+                        return None;
+                    }
+                },
+                '#' => {
+                    k += 1;
+                    if in_segment {
+                        segment_size += 1;
+                        if segment_size > crc_size {
+                            // ### is too large.
+                            //eprintln!(   "overflow span {segment_size}/{crc_size} at {k}");
+                            return None;
+                        }
+                    } else {
+                        in_segment = true;
+                        segment_size = 1;
+                    }
+                },
+                _ => panic!("Malformed input"),
+            }
+        }
+        // We finished parsing the string without consuming all the iterator
+        // or reaching a final "."
+        // the pattern matches only the beginning, so not a complete prefix.
+        //eprintln!("   Finish, not matched");
+        None
+        
+    }
+
+
+    // brute_find_all_prefix + argt_recursive_test are still too "bruteforcy" and probably recomputes
+    // the same similar things over and over (via different paths).
+    // We need to memoize a few results to speed-up again.
+
+    // "public" entry point
+    fn find_all_prefix(memo: &mut PrefixMemo, condition_state: &str, max_offset: usize, crc: &[i64], level: i32) -> Prefixes {
+        let key = PrefixQuery{ condition_state, max_offset, crc };
+        // only the handle is copied out of the memo, needed again by the recursion
+        memo.call(&key, |memo, key| Self::brute_find_all_prefix(memo, key.condition_state, key.max_offset, key.crc, level)).clone()
+    }
+
+    
+    // Find all the arrangements of the crc list, starting from the beginning of the string
+    // up to some index inside, and ending with "#."
+    // The return value maps the valid "size" of prefix-strings with their number of arrangements.
+    // those size include the final "."
+    // This functions works only for reasonably small input strings and crc vectors.
+    fn brute_find_all_prefix(memo: &mut PrefixMemo, condition_state: &str, max_offset: usize, crc: &[i64], level: i32) -> Prefixes {
+
+        if level == 0 {
+            //eprintln!("Called top-level brute for {condition_state} [cap {max_offset}] {:?}", crc);
+        }
+        let mut hm = HashMap::<usize,i64>::new();
+
+        if let Some((left, right)) = condition_state.split_once('?') {
+            if left.is_empty() {
+                // '?' at first char, we have done nothing yet, nothing to check
+                // (would panic when indexing chars inside)
+                //eprintln!("Trying {condition_state} for {:?}", crc);
+            } else {
+                if let Some(n) = Self::get_exact_prefix(condition_state, crc) {
+                    //eprintln!("recursed {condition_state} matches {:?} at length {n} ({left}) ", &crc);
+                    hm.insert(n, 1);
+                    return Rc::new(hm); // actual positive result
+                } // else continue
+
+                if left.find('#').is_none() && left.len() >= max_offset {
+                    // We got too far, no need to iterate more
+                    //eprintln!("early return for'{left}/?/{right}' over {max_offset}");
+                    return Rc::new(hm); // early empty
+                }
+                //eprintln!("checking {condition_state} for {:?}", crc);
+                // count the damaged spans we have so far before the first '?'.
+                let damaged_left:Vec<&str> = left.split('.').collect();
+                let mut damaged_left:Vec<i64> = damaged_left.iter().map(|s| s.len() as i64).filter(|len| *len != 0).collect();
+
+                // as crc is a prefix, it will ignore all additional ".#" we may create when replacing "?".
+                if damaged_left.len() > crc.len() {
+                    // This means we have recursed into a wrong direction.
+                    return Rc::new(hm); // empty
+                }
+                // if the last span was just before '?' then it can extend more in the next iteration.
+                // else (there is a '.' explicitely cutting it) the last span is at its exact final value.
+                let last_char = left.chars().last().unwrap(); // we could also force an indexing to len()-1 as it's ascii and not utf8
+                
+                let mut crc_begin:Vec<i64> = crc.to_vec(); // fixme: no better way to extract "view" ? chunks() gives a splice
+                // with non-working pop() or comparison with damaged_left later.
+                crc_begin.truncate(damaged_left.len()); // split at the first elements
+                if last_char == '.' {
+                    // all values must match
+                    if crc_begin != damaged_left {
+                        //eprintln!("partial test (1,==) at {condition_state} can not match {:?}", crc);
+                        return Rc::new(hm); // Early return, impossible
+                    }
+                } else {
+                    // last value can be >=, others must match
+                    let last = crc_begin.len()-1;
+                    if damaged_left[last] > crc_begin[last]  {
+                        //eprintln!("partial test (2,>) at {condition_state} can not match {:?}", crc);
+                        return Rc::new(hm);
+                    }
+                    // now compare exactly the rest of the elements
+                    crc_begin.pop();
+                    damaged_left.pop();
+                    if crc_begin != damaged_left {
+                        //eprintln!("partial test at {condition_state} can not match {:?}:: d_left ={:?}, c_begin={:?}", crc, damaged_left, crc_begin);
+                        //eprintln!("was {condition_state}, {:?}", crc);
+                        return Rc::new(hm); // Early return, impossible
+                    }
+                }
+            }
+
+            // test both replacement of our '?'
+            let damaged = Self::find_all_prefix(memo, &format!("{left}#{right}"), max_offset, crc, level+1);
+            let operational = Self::find_all_prefix(memo, &format!("{left}.{right}"), max_offset, crc, level+1);
+            // when one of them has no arrangement, share the prefixes of the other one
+            if operational.is_empty() {
+                return damaged;
+            }
+            if damaged.is_empty() {
+                return operational;
+            }
+            // else add values of both # and . versions
+            hm = (*damaged).clone();
+            for (&k,&v) in operational.iter() {
+                *hm.entry(k).or_insert(0) += v;
+            }
+
+            Rc::new(hm)// This is the merge/add of all the inner recursions results.
+
+
+        } else {
+            //eprintln!("Reached terminal {condition_state}");
+            // terminal string with no '?'
+            // we are leaf: check if we match crc.
+            match Self::get_exact_prefix(condition_state, crc) {
+                None => {
+                    //eprintln!("terminal {condition_state} doesn't match {:?}", &crc);
+                } , // found 0 arrangement
+                Some(n) => {
+                    //eprintln!("terminal {condition_state} matches {:?} at length {n} ", &crc);
+
+                    hm.insert(n, 1); },  // found 1 arrangement
+            }
+            Rc::new(hm)
+        }
+    }
+
+    // after we finish a complete brute_find_all_prefix / get_exact_prefix, the rest of the string
+    // should not match any more ### segment.
+    fn check_empty_postfix(condition_state: &str, after: usize) -> bool {
+        if condition_state.len() == after + 1 {
+            // nothing
+            true
+        } else {
+            // Not OK: any # would need at least another (1) in the crc
+            // OK: nothing but . and maybe ? (that will match with the empty crc
+            // by being all replaced by '.', so only 1 possible case
+            // which will not change the previous number of arrangements)
+            ! condition_state[after..].contains('#')
+        }
+    }
+
+
+    fn argt_recursive_test(&mut self, condition: &str, crc: &[i64], level: i32) -> i64 {
+        // split the crc into two parts (if possible)
+        let mut crc_head = crc.to_vec();
+        let crc_split_index = if crc.len() <= self.split_unit {
+            crc.len()  // this will be our final recursion
+        } else {
+            self.split_unit
+        };
+        let crc_tail = crc_head.split_off(crc_split_index); // may be empty if final recursion
+        //eprintln!("Split by {:?} and {:?}", crc_head, crc_tail);
+
+        let total = condition.len();
+        // compute the minimal number of characters occupied by all crc():
+        let damaged:i64 = crc.iter().sum(); // all # characters
+        let operational_min = crc.len() - 1; // all intervals between # must count at least one '.'
+        // The first "#"  must be inside condition [..max_offset] as anything after will
+        // not have enough room for the rest.
+        //eprintln!("trying {total} - {damaged} - {operational_min}");
+        let occupied_min = damaged as usize + operational_min;
+        if occupied_min >= total {
+            // previous "map" already went too far, no room left.
+            return 0;
+        }
+        // else, may be possible
+        let max_offset:usize = total - occupied_min;
+
+        // variable max_offset messes with Memoization.
+        // Since speedup my memo is greater than the smaller speedup by offset early exit alone,
+        // we don't use it in final version.
+        //let max_offset = total - occupied_min;
+        
+        
+
+        // shared with the memo, which is needed again by the recursion below
+        let hm = Self::find_all_prefix(&mut self.memo_prefix,
+                                       condition,
+                                       max_offset,
+                                       &crc_head,
+                                       if level<= 1 { 0 } else {level}
+        );
+
+        if level<= 1 {
+            //eprintln!("At @[{level}] Prefixes map = {:?}", hm);
+        }
+        
+        let mut arrangements = 0;
+        for (&k,&v) in hm.iter() {
+
+            if crc_tail.is_empty() {
+                // nothing else to find
+                if Self::check_empty_postfix(condition, k) {
+                    // actually nothing else wants to be found
+                    arrangements += v ;
+                }
+                // else: dead-end search (0 valid new arrangements)
+            } else {
+                // factorize this prefix with the next recursion level on
+                // the rest of the string and the rest of the crc
+                let (_, condition_tail) = condition.split_at(k);
+                arrangements += v * self.argt_recursive_test(condition_tail, &crc_tail, level+1);
+            }
+        }
+        //eprintln!("Level: {arrangements}");
+        arrangements
+    }
+
+    // count the possible arrangements
+    fn arrangements(&mut self, condition: &str, crc: &[i64]) -> i64 {
+        // Add a terminal "." to ensure the invariant that any pattern can end
+        // with "#." and not just "#", even at the end of original input with "#" or "?"
+        // Any leading or trailing sequence of fixed '.' does not change the possible
+        // permutations and the final result.
+        let condition = format!("{condition}.");
+
+        self.argt_recursive_test(&condition, crc, 0)
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        debug!("Parsing {l}");
+        if let Some((condition,crc)) = l.split_once(" ") {
+            check_condition(l, condition)?;
+            let crc:Vec<i64> = crc.split(',').map(|x| parse::number(l, x)).collect::<Result<_, _>>()?;
+            // try to multiply by 5 the input and check if the brute-force is still working...
+            // It would have been nice if it was just giving "part_1 ^ 5" but the additionnal
+            // "?" actually allows new combinations between copies.
+            
+            let condition = format!("{}?{}?{}?{}?{}", condition, condition, condition,
+                                    condition, condition);
+            let crc = crc.repeat(5);
+
+
+            //let condition = format!("{}?{}?{}", condition, condition, condition);
+            //let crc = crc.repeat(3);
+
+            let arg = self.arrangements(&condition, &crc);
+            debug!("{} : => argt {} (memo: {})", l, arg, self.memo_prefix.stats());
+            // Tried to reuse the memo between samples lines. Ended up filling all my memory
+            // after 200 lines.
+            self.memo_prefix.clear(); //
+            self.memo_prefix.reset_stats();
+            self.total += arg;
+        } else {
+            return Err(ParseError::at_end(l, "<condition> <damaged groups>"));
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+pub const PARAMS: &[aoc_common::Param] = &[aoc_common::Param {
+    name: "split_unit",
+    help: "size of the chunks of the damaged groups list (default 2)",
+    min: 1,
+    max: 16,
+}];
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    let split_unit = params.get("split_unit").unwrap_or(2);
+    aoc_common::run(Solver::new(split_unit as usize), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
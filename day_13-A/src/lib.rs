@@ -0,0 +1,156 @@
+/*
+https://adventofcode.com/2023/day/13
+--- Day 13: Point of Incidence ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_grid::{BitBoard, BitRow};
+use aoc_common::parse;
+use aoc_common::debug;
+use aoc_common::Answer;
+
+
+/*
+ * By looking at the input:
+ * 1) the reflexion center
+ * is not just at Middle +/-1 : it could be also
+ * juste at line 1.5, reflecting together lines
+ * 1 and 2 and leaving all lines 3-10 with nowhere
+ * to reflect outside the limit.
+ * So an exhaustive search would do all [1..M/2]
+ * and not just the pair (Middle-1, Middle+1)
+
+ * 2) the input patterns are small, smaller than 20
+ * in any direction. So it's possible to just map them
+ * into bits in standard integers to speed-up equality
+ * comparisons.
+
+ * Impl: lots of small off-by-one errors when setting
+ * the loop limits and the out-of-pattern tests and the
+ * reflection out-of-bounds checks and...
+ */
+
+
+// Solver for this particular problem
+
+// Map 1 point "#" to 1 bit, for patterns of any size
+type LineData = BitRow;
+
+// The matrix of points is represented in duplicate
+// representations: all its lines, and all its rows,
+// for easier comparison.
+#[derive(Debug)]
+struct Pattern {
+    rows: Vec<LineData>,
+    cols: Vec<LineData>,
+}
+
+impl Pattern {
+    fn new(board: &BitBoard) -> Self {
+        Self {
+            rows: board.rows().to_vec(),
+            cols: board.columns().collect(),
+        }
+    }
+
+    // return the last index before the reflexion axis,
+    // indexed by 0. Add 1 for the Puzzle indexing.
+    fn get_reflected(vec: &[LineData]) -> Option<usize> {
+
+        // reflexion axis can be anywhere until the last index
+        for limit in 0..vec.len()-1 {
+            let mut reflected = true;
+            //eprintln!("Checking reflexion axis {limit}");
+            for k in 0..=limit {
+                let reflected_k = limit+k+1; // +1 !
+                if reflected_k >= vec.len() {
+                    // not a "break": if limit > len/2,
+                    // the first index are always out of bound
+                    // and we need to reach the first foldable ones later.
+                    //eprintln!(" axis {}/{reflected_k} OOB", limit-k);
+                    continue;
+                }
+                if vec[limit-k] != vec[reflected_k] {
+                    //eprintln!(" axis {}/{reflected_k} are different", limit-k);
+                    reflected = false;
+                    break;
+                } else {
+                    //eprintln!(" axis {}/{reflected_k} are same, continuing", limit-k);
+                }
+            }
+            if reflected {
+                return Some(limit);
+            }
+        }
+
+        None
+    }
+}
+
+
+struct Solver {
+    total: usize,
+}
+
+
+
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+
+    fn process_pattern(&mut self, pat: &Pattern) {
+        debug!("Consummed pattern: {:?}", pat);
+
+        if let Some(row_reflection) = Pattern::get_reflected(&pat.rows) {
+            debug!("Pattern has reflection over horizontal line {row_reflection}+1");
+            self.total += 100 * (row_reflection + 1);
+        } else if let Some(col_reflection) = Pattern::get_reflected(&pat.cols) {
+            debug!("Pattern has reflection over vertical column {col_reflection}+1");
+            self.total += col_reflection + 1;
+        } else {
+            panic!("No reflexion found for pattern !!");
+        }
+
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process all the patterns, separated by an empty line
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        for block in parse::blocks(&lines) {
+            let tiles = block.grid("'.' or '#'", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })?;
+            // the rows are stored as bits of a LineData, the columns
+            // are their transpose
+            let board = BitBoard::from_fn(tiles[0].len(), tiles.len(), |x, y| tiles[y][x]);
+            self.process_pattern(&Pattern::new(&board));
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
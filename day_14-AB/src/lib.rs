@@ -0,0 +1,340 @@
+/*
+https://adventofcode.com/2023/day/14
+--- Day 14: Parabolic Reflector Dish ---
+ */
+
+use std::io::{self, BufRead};
+use aoc_common::ParseError;
+use std::ops::Range;
+use aoc_algo::{cycle, IntervalSet};
+use aoc_grid::{BitBoard, BitRow};
+use aoc_common::log::Level;
+use aoc_common::{debug, info, log_enabled, trace, warn};
+use aoc_common::Answer;
+/*
+
+A naive Algo to roll boulders until they block would
+be easy to do with an exhaustive double iteration on each
+column (find each blocking point then find each "O" to accumulate
+on the point). It would probably be something like O(M*N)
+with M,N the map dimensions, not too crazy. It would just be
+a bit tedious to write.
+
+Reading part 1, I guess that part 2 will ask
+to do the same thing again in S/W/E directions, probably
+repeatedly until a minimal total load is found.
+Better to have a reasonably fast algo for part 1 from the start.
+ */
+
+
+/*
+Instead of low-level iteration on each boulder, it is possible to
+pre-process the map into a set of free ranges between square rocks:
+For example for 1 line (on the E-W axis)
+      ..##.....#...#
+      ^^  ^---^ ^-^
+=> [1-2]  [5-9] [11-13]
+
+when computing the rolling, count the number of O inside each range
+(ignore their position). After rolling, on the final map
+each range will be replaced by their consecutive numbers of O at
+the start or end (depending on the direction).
+
+ An optimized version of this can be done with bit representation
+(like in the day 13) and various bit-shifting operations instead of
+settings a range of N elements in a bool slice[].
+Actual puzzle input is of size 100x100, the rows are aoc_grid BitRows
+which fit any size.
+
+For expected Part2, after rolling, and when we will want to perform different rolling axis direction,
+there will need to use 2 matrix representations of the map (transpose of each other)
+for easier processing.
+
+*/
+
+
+/* 
+ Actual Part 2 : Do N/W/S/E titling 1 BILLION TIMES
+ - I would have imagined that it would converge at some point
+   to a stable configuration, but no. Instead it enters a ...
+   cycle of loads value after around 85 tilt cycles, with
+   a cycle length of 11 (in my input sample) or 21.
+The sample input in the puzzle has a cycle of length 8
+
+Once the cycle is detected it's only a matter of doing 
+(1 BILLION - cycle start) modulo cycle_length
+to pick the correct value.
+
+Cycle detection consists of finding our last computed state
+somewhere in the previous cycles. As each state derive
+mechanically from the previous one, if we have 
+State(n) == State(p), then for any i
+State(n+i) == State(p+i).
+
+We can't just compare the numerical "load" value because
+different boulder map configuration may lead to the same
+total value, so state must include the exact map data
+(or a robust hash) (or just a simple xor of the map, this seems
+to work...)
+
+
+However it looks like my algo found a cycle of length 22 instead of visually
+11, but the load values are the same and duplicated, so no actual difference
+even if the binary map state seems different ?
+*/
+
+
+// Used only for initial parsing.
+#[derive(PartialEq,Clone,Copy)]
+enum Tile {
+    Empty,
+    Rock,
+    Boulder,
+}
+
+use Tile::*;
+
+
+// The maps will exist in two redundant representation, one as
+// a vec of rows, and as a vec of columns.
+
+// The explicit map of all boulders, 1 bit = 1 boulder.
+type BoulderMap = BitBoard;
+// A representation of all the free spaces between # rocks as sets of [..] range coordinates.
+type RangeMap = Vec<IntervalSet<u32>>;
+
+
+struct Solver {
+    total: i64,
+    hrmap: RangeMap,
+    vbmap: BoulderMap,
+    vrmap: RangeMap,
+    part_2: bool,
+}
+
+impl Solver {
+    fn new(part_2: bool) -> Self {
+        Self{total : 0,
+             hrmap: Vec::new(),
+             vbmap: BitBoard::new(0, 0),
+             vrmap: Vec::new(),
+             part_2,
+        }
+    }
+
+    // This could have been parsed directly from the textual string instead
+    // of the intermediate representation, but with too much code nesting
+    // in process()...
+    
+    // parse the matrix of tiles into compact representations.
+    // return the "rows" version.
+    fn tile_map_into_bouldermap(map: &[Vec<Tile>]) -> BoulderMap {
+        // first boulder gets bit 0, second bit 1 etc...
+        let bmap = BitBoard::from_fn(map[0].len(), map.len(), |x, y| map[y][x] == Boulder);
+        //eprintln!("Mapped O into {:?}", bmap);
+
+        bmap
+    }
+
+    fn tile_map_into_rangemap(map: &Vec<Vec<Tile>>) -> RangeMap {
+        let mut rmap: RangeMap = Vec::new();
+
+        for line in map {
+            // the whole line except the # rocks
+            let rocks: IntervalSet<u32> = line.iter()
+                .enumerate()
+                .filter(|(_,t)| **t == Rock)
+                .map(|(idx,_)| idx as u32..idx as u32 + 1)
+                .collect();
+            rmap.push(IntervalSet::from(0..line.len() as u32).difference(&rocks));
+        }
+        //eprintln!("Mapped #..# into {:?}", rmap);
+        
+        rmap
+    }
+
+    // transpose the row/columns in the bitfield representation
+    // of a boulder map.
+    fn transpose_bouldermap(bmap: &BoulderMap) -> BoulderMap {
+        bmap.transpose()
+    }
+
+    // all bits from rstart..rend are set to 1.
+    fn bitmask_from_range(width: usize, r: &Range<u32>) -> BitRow {
+        BitRow::from_range(width, r.start as usize..r.end as usize)
+    }
+
+    // Apply the boulder/range computing towards beginning or
+    // end of axis and return a new bouldermap with items stacked.
+    fn tilt_bouldermap_to_direction(bmap: &BoulderMap,
+                                    rmap: &RangeMap,
+                                    to_0: bool) -> BoulderMap {
+        // "lines" are relatives to the orientation of this.
+        // bmap/rmap pair. It can correspond to the input rows,
+        // or to its transposed columns when tilting North/South.
+
+        let width = bmap.width();
+        let mut tilted = BoulderMap::new(width, bmap.height());
+        for (line, ranges) in rmap.iter().enumerate() {
+            let bline = bmap.row(line);
+            let moved_boulders_line = tilted.row_mut(line);
+            for r in ranges.iter() {
+                let bitmask = Self::bitmask_from_range(width, r);
+                // popcount of each word of the row
+                let count = (bline & &bitmask).count_ones() as u32;
+                let boulder_moved = if to_0 {
+                    // all counts should be starting at range start
+                    Self::bitmask_from_range(width, &(r.start..(r.start+count)))
+                } else {
+                    // all counts should be ending at range end
+                    Self::bitmask_from_range(width, &((r.end-count)..r.end))
+                };
+                *moved_boulders_line |= &boulder_moved;
+            }
+        }
+
+        tilted
+    }
+
+    // Perform only tilt to north once.
+    fn postprocess_part_1(&mut self) {
+        // Perform Tilting.
+        // Part 1 : Tilting to north means using the transposed (vertical) map
+        // and tilt to 0.
+        let tilted_north = Self::tilt_bouldermap_to_direction(&self.vbmap, &self.vrmap, true);
+        //eprintln!("North tilt map: (columns) {:?}", tilted_north);
+
+        // Compute score. Re-transpose the map to get vertical again.
+        let tmap = Self::transpose_bouldermap(&tilted_north);
+        //eprintln!("North tilt map: (rows) {:?}", tmap);
+
+        self.total = self.north_load(&tmap);
+    }
+
+
+    // Do the 4 tilts N -> W -> S -> E, on the vertical map
+    fn spin_cycle(&self, vbmap: &BoulderMap) -> BoulderMap {
+        let tilted_north = Self::tilt_bouldermap_to_direction(vbmap, &self.vrmap, true);
+        let hbmap = Self::transpose_bouldermap(&tilted_north);
+
+        let tilted_west = Self::tilt_bouldermap_to_direction(&hbmap, &self.hrmap, true);
+        let vbmap = Self::transpose_bouldermap(&tilted_west);
+
+        let tilted_south = Self::tilt_bouldermap_to_direction(&vbmap, &self.vrmap, false);
+        let hbmap = Self::transpose_bouldermap(&tilted_south);
+
+        let tilted_east = Self::tilt_bouldermap_to_direction(&hbmap, &self.hrmap, false);
+        Self::transpose_bouldermap(&tilted_east)
+    }
+
+    // Load on the north beams, from the rows of the map
+    fn north_load(&self, hbmap: &BoulderMap) -> i64 {
+        let mut load:i64 = 0;
+        // count with the original horizontal map dimension.
+        for line in 0..self.hrmap.len() {
+            let coefficient = (self.hrmap.len() - line) as i64; // 1-indexing and not 0-indexin
+            let boulder_row_count = hbmap.row(line).count_ones() as i64;
+            load += coefficient * boulder_row_count;
+        }
+        load
+    }
+
+    fn postprocess_part_2(&mut self) {
+        // The boulders end up repeating the same positions after some cycles.
+        // The whole map is the state, a fingerprint such as (load, xor-of-rows)
+        // could match two different maps.
+        let Some(cycle) = cycle::find_cycle(self.vbmap.clone(), |vbmap| self.spin_cycle(vbmap), 2000) else {
+            warn!("WARNING ! cycle not found for this test case.");
+            return;
+        };
+        info!("found cycle starting at {}, len {}", cycle.start, cycle.len);
+        if log_enabled!(Level::Trace) {
+            for (k, vbmap) in cycle.states.iter().enumerate() {
+                trace!("Load after {k} cycles: {}", self.north_load(&Self::transpose_bouldermap(vbmap)));
+            }
+        }
+
+        let billion = 1_000_000_000;
+        self.total = self.north_load(&Self::transpose_bouldermap(cycle.state_at(billion)));
+        info!("Load for Billionth iteration is at cycle[{}] = {}", cycle.index_at(billion), self.total);
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process all text input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let mut map: Vec<Vec<Tile>> = Vec::new();
+        
+        for (n, input) in aoc_common::lines(reader).enumerate() {
+            let input_clean = &input?;
+            let line: Vec<Tile> = input_clean.chars()
+                .enumerate()
+                .map(|(i, c)| match c {
+                    '#' => Ok(Rock),
+                    'O' => Ok(Boulder),
+                    '.' => Ok(Empty),
+                    _ => Err(ParseError::new(i + 1, "'#', 'O' or '.'", c).with_line(n + 1)),
+                })
+                .collect::<Result<_, _>>()?;
+            // all the rows have the same size
+            if map.first().is_some_and(|m| m.len() != line.len()) {
+                return Err(ParseError::new(1, "rows of the same size", input_clean.as_str())
+                           .with_line(n + 1).into());
+            }
+            map.push(line);
+        }
+        if map.is_empty() {
+            return Err(ParseError::new(1, "a map", "").with_line(1).into());
+        }
+
+        let bmap_h = Self::tile_map_into_bouldermap(&map);
+        let rmap_h = Self::tile_map_into_rangemap(&map);
+
+        let bmap_v = Self::transpose_bouldermap(&bmap_h);
+        // Transpose map to get the vertical rangemap
+        let mut tmap = vec![Vec::with_capacity(map.len()); map[0].len()];
+        for l in map {
+            for i in 0..l.len() {
+                tmap[i].push(l[i]);
+            }
+        }
+        let rmap_v = Self::tile_map_into_rangemap(&tmap);
+
+        self.hrmap = rmap_h;
+        self.vbmap = bmap_v;
+        self.vrmap = rmap_v;
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        if self.part_2 {
+            debug!("doing part 2");
+            self.postprocess_part_2();
+        } else {
+            debug!("doing part 1");
+            self.postprocess_part_1();
+        }
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+// only the part to solve, given with --part
+pub const PARAMS: &[aoc_common::Param] = &[];
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(params.part_2()), reader)
+}
+
+pub fn solve_a(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
+
+pub fn solve_b(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new_part_2(PARAMS))
+}
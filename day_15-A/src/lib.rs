@@ -0,0 +1,67 @@
+/*
+https://adventofcode.com/2023/day/15
+--- Day 15: Lens Library ---
+(hash & hashmap)
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::ParseError;
+use aoc_common::Answer;
+
+// Solver for this particular problem
+
+struct Solver {
+    total: u32,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+
+
+    // could be rewritten in a one-liner .map().fold()
+    fn hash(l: &str) -> u8 {
+        let mut h:u32 = 0;
+        for c in l.chars() {
+            let ascii = c as u32;
+            h += ascii;
+            h *= 17;
+            h &= 0xff;
+        }
+        (h & 0xff) as u8
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process the input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        for step in l.split(',') {
+            self.total += Self::hash(step) as u32;
+        }
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+
+    let s = Solver::new();
+
+    // Only 1 long line of input
+    aoc_common::run(s, reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,107 @@
+/*
+https://adventofcode.com/2023/day/15
+--- Day 15: Lens Library ---
+(hash & hashmap)
+ */
+
+
+use std::io::{self, BufRead};
+use std::collections::HashMap;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{debug, trace};
+use aoc_common::Answer;
+
+// Solver for this particular problem
+
+
+type LensBox = Vec<String>;
+
+struct Solver {
+    total: u32,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+
+    fn hash(l: &str) -> u8 {
+        (l.chars().fold(0, |acc, c|  ((acc + (c as u32)) * 17 ) & 0xff) & 0xff) as u8
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process the input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        let mut lenses:HashMap<String, u32> = HashMap::new(); // all the lenses ("label" string) with their focal length
+        let mut boxes = vec![ LensBox::new() ; 256]; // The boxes containing lenses of identical hash
+
+        for step in l.split(',') {
+            if step.contains('-') { // lab-
+                if let Some((label,_)) = step.split_once('-') {
+                    let lnum = Self::hash(label);
+                    if let Some(pos) = &boxes[lnum as usize].iter().position(|s| s == label) {
+                        boxes[lnum as usize].remove(*pos);
+                    }
+                    // Lenses that are not in a box don't count for final focusing power
+                    lenses.remove(&String::from(label));
+                }
+            } else { // "lab=x"
+                if let Some((label,focal_length)) = step.split_once('=') {
+                    let focal_length = parse::number(l, focal_length)?;
+                    let lnum = Self::hash(label);
+                    if boxes[lnum as usize].iter().position(|s| s == label).is_none() {
+                        boxes[lnum as usize].push(String::from(label));
+                    }
+                    lenses.insert(String::from(label), focal_length);
+                } else {
+                    return Err(ParseError::at(l, step, "<label>=<focal length> or <label>-"));
+                }
+            }
+        }
+
+        // debug
+        debug!("Boxes = ");
+        for b in &boxes {
+            debug!("B[] = {:?}", b);
+        }
+        debug!("Lenses = {:?}", lenses);
+
+        // Compute focusing power
+        // arbitrary order is ok
+        self.total = lenses.iter()
+            .fold(0, | acc, (k,v) | {
+                let boxnum = Self::hash(k);
+                if let Some(slotposition) = &boxes[boxnum as usize].iter().position(|s| s == k) {
+                    let focuspower = (boxnum as u32 + 1) * (*slotposition as u32 + 1) * v;
+                    trace!("{k} has focusing power of [box {boxnum}+1] {focuspower}");
+                    acc + focuspower
+                } else {
+                    panic!("Lense {} is not in a box, should have been remove'd", k);
+                }
+            });
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+
+    let s = Solver::new();
+
+    // Only 1 long line of input
+    aoc_common::run(s, reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
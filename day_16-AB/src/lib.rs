@@ -0,0 +1,241 @@
+/*
+https://adventofcode.com/2023/day/16
+--- Day 16: The Floor Will Be Lava ---
+Energize !
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_grid::{Direction4, Grid};
+use std::cmp;
+use aoc_common::trace;
+use aoc_common::Answer;
+
+// Solver for this particular problem
+
+#[derive(Clone,Copy)]
+enum Tile {
+    Empty,
+    SplitterH,
+    SplitterV,
+    MirrorSlash,
+    MirrorAnti,
+}
+use Tile::*;
+
+use Direction4::*;
+
+// For transfer, mirror or splitting:
+// Indicate a single direction or a split directions pair.
+#[derive(Clone,Copy)]
+enum AnyDirections {
+    OneDirection(Direction4),
+    TwoDirections(Direction4,Direction4),
+}
+use AnyDirections::*;
+
+struct Solver {
+    total: i32,
+    map: Grid<Tile>,
+    //energy: Vec<Vec<i32>>,  // 
+    directions: Grid<u8>,  // for each tile, a bitmask indicating if a
+    // beam has entered it with one of the four directions.
+    // non-zero indicated "energized" status, exact value indicate end of recursion
+    part_2: bool,
+}
+
+impl Solver {
+    fn new(part_2: bool) -> Self {
+        Self{total : 0,
+             map : Grid::new(0, 0, Empty),
+             directions: Grid::new(0, 0, 0),
+             part_2,
+        }
+    }
+
+    fn get_tile(&self, x:usize, y:usize) -> Tile {
+        self.map[(x,y)]
+    }
+
+    // return true if the new bits are "new", 
+    // false if they were already set.
+    fn update_direction_bits(&mut self, x:usize, y:usize, b:u8) -> bool {
+        let v = self.directions[(x,y)];
+        if (v & b) == b {
+            false
+        } else {
+            self.directions[(x,y)] |= b;
+            true
+        }
+            
+    }
+
+    
+    // If splitting: return the two split directions;
+    // if not splittiong: return the single new direction
+    fn tile_to_directions(t: Tile, d: Direction4) -> AnyDirections {
+        match t {
+            Empty => OneDirection(d),
+            SplitterH => match d {
+                Left | Right => OneDirection(d),
+                Up | Down => TwoDirections(Left, Right),
+            },
+            SplitterV => match d {
+                Left | Right => TwoDirections(Up, Down),
+                Up | Down => OneDirection(d),
+            },
+            MirrorSlash => match d {
+                Left => OneDirection(Down),
+                Right => OneDirection(Up),
+                Up => OneDirection(Right),
+                Down => OneDirection(Left),
+            },
+            MirrorAnti => match d {
+                Left => OneDirection(Up),
+                Right => OneDirection(Down),
+                Up => OneDirection(Left),
+                Down => OneDirection(Right),
+            },
+        }
+    }
+
+    fn direction_to_bitmask(d: Direction4) -> u8 {
+        match d {
+            Left => 1,
+            Right => 2,
+            Up => 4,
+            Down => 8,
+        }
+    }
+    
+    // Ray enter (x,y) tile with direction d.
+    // Recursively follow when splitting and update path.
+    fn follow_ray(&mut self, mut x: usize, mut y:usize, mut d: Direction4) {
+        loop {
+            let d8 = Self::direction_to_bitmask(d);
+            if !self.update_direction_bits(x, y, d8) {
+                //eprintln!("Beam already reached {x},{y} via direction {d8}, break");
+                break;
+            }
+            let new_direction = Self::tile_to_directions(self.get_tile(x,y), d);
+            match new_direction { // follow single beam
+                OneDirection(d1) => {
+                    match self.map.step_towards(x, y, d1) {
+                        Some(next) => (x,y) = next,
+                        None => break, // Ray exits
+                    }
+                    d = d1;
+                }, 
+                TwoDirections(d1,d2) => {  // follow split beam
+                    if let Some((x1,y1)) = self.map.step_towards(x, y, d1) {
+                        self.follow_ray(x1, y1, d1);
+                    }
+                    if let Some((x2,y2)) = self.map.step_towards(x, y, d2) {
+                        self.follow_ray(x2, y2, d2);
+                    }
+                    break;
+                },
+            }
+        }
+    }
+    
+    fn postprocess_1(&mut self) {
+        self.directions = Grid::new(self.map.width(), self.map.height(), 0);
+
+        // Enter the top corner coming from top-left,
+        // and follow recursively.
+        self.follow_ray(0, 0, Right);
+
+        self.total = self.get_energized_count();
+        trace!("{:?}", self.directions);
+    }
+
+
+    fn reset_directions_map(&mut self) {
+        self.directions.fill(0);
+    }
+
+    fn get_energized_count(&self) -> i32 {
+        self.directions.count(|&v| v != 0) as i32
+    }
+        
+    // part 2: try all edge tiles
+    fn postprocess_2(&mut self) {
+        let mut max_total:i32 = 0;
+
+        self.directions = Grid::new(self.map.width(), self.map.height(), 0);
+
+        let right_col = self.map.width()-1;
+        let bottom_row = self.map.height()-1;
+        // test top and bottom rows
+        for xstart in 0..=right_col {
+            self.reset_directions_map();
+            self.follow_ray(xstart, 0, Down);
+            max_total = cmp::max(max_total, self.get_energized_count());
+
+            self.reset_directions_map();
+            self.follow_ray(xstart, bottom_row, Up);
+            max_total = cmp::max(max_total, self.get_energized_count());
+        }
+
+        // test left and right columns
+        for ystart in 0..=bottom_row {
+            self.reset_directions_map();
+            self.follow_ray(0, ystart, Right);
+            max_total = cmp::max(max_total, self.get_energized_count());
+
+            self.reset_directions_map();
+            self.follow_ray(right_col, ystart, Left);
+            max_total = cmp::max(max_total, self.get_energized_count());
+        }
+
+        self.total = max_total;
+
+
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+        let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        self.map = Grid::parse(&lines, "a tile", |c| match c {
+            '.' => Some(Empty),
+            '-' => Some(SplitterH),
+            '|' => Some(SplitterV),
+            '/' => Some(MirrorSlash),
+            '\\' => Some(MirrorAnti),
+            _ => None,
+        })?;
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        if self.part_2 {
+            self.postprocess_2();
+        } else {
+            self.postprocess_1();
+        }
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+// only the part to solve, given with --part
+pub const PARAMS: &[aoc_common::Param] = &[];
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(params.part_2()), reader)
+}
+
+pub fn solve_a(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
+
+pub fn solve_b(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new_part_2(PARAMS))
+}
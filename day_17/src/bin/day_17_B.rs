@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::main(day_17::part_b::run);
+}
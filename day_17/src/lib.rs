@@ -0,0 +1,8 @@
+/*
+https://adventofcode.com/2023/day/17
+--- Day 17: Clumsy Crucible ---
+(weighted graph traversal with constraints)
+ */
+
+pub mod part_a;
+pub mod part_b;
@@ -0,0 +1,125 @@
+/*
+https://adventofcode.com/2023/day/17
+--- Day 17: Clumsy Crucible ---
+(weighted graph traversal with constraints)
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_algo::search;
+use aoc_grid::{Direction4, Grid};
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i32,
+    heat_loss: Grid<u8>,
+}
+
+use Direction4::*;
+
+/* The "3 moves limit" can't be handled by Dijkstra on the tiles alone (which give
+ * an incorrect final result): the same tile must be visitable again for each way
+ * to reach it. So the search state is the tile with the direction and the number
+ * of successive moves in that direction reaching it.
+
+    With only the tile and 1 u8 to differentiate 1/2/3 moves (whatever directions):
+     * 1258 : That's not the right answer; your answer is too high
+
+    With full dimensionnality direction + exact length:
+    1244
+    That's the right answer! You are one gold star closer
+ */
+#[derive(Clone,Copy,Debug,Eq,PartialEq,Hash)]
+struct Crucible {
+    x: usize,
+    y: usize,
+    d: Direction4,
+    len: u8, // 0 for the start only
+}
+
+// Get possible next direction from a starting point:
+// 2 perpendicular branching,
+// and if from_len is not yet 3 : continue direction
+fn get_possible_next(d: Direction4, from_len:u8) -> Vec<(Direction4,u8)> {
+    // perpendicular turns
+    let (a,b) = (d.turn_left(), d.turn_right());
+    if from_len < 3 {
+        vec![(a,1), (b,1), (d, from_len+1)]
+    } else {
+        vec![(b,1), (a,1)]
+    }
+}
+
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+             heat_loss: Grid::<u8>::new(1,1,0), // Arbitrary size before replacing it after parsing
+        }
+    }
+
+    // The crucible moves from c, with the heat loss of the tile entered
+    fn moves(&self, c: &Crucible) -> Vec<(Crucible, i32)> {
+        get_possible_next(c.d, c.len).into_iter()
+            .filter_map(|(d, len)| {
+                let (x, y) = self.heat_loss.step_towards(c.x, c.y, d)?;
+                Some((Crucible { x, y, d, len }, self.heat_loss[(x, y)] as i32))
+            })
+            .collect()
+    }
+
+    fn least_heat_loss(&self) -> i32 {
+        let (w, h) = (self.heat_loss.width(), self.heat_loss.height());
+        // ignore heat_loss of the starting tile
+        let start = Crucible { x: 0, y: 0, d: Right, len: 0 };
+        let Some(path) = search::dijkstra([start], |c| self.moves(c), |c| c.x + 1 == w && c.y + 1 == h) else {
+            panic!("No path reaches the destination");
+        };
+        info!("Dijkstra converged in {} iterations", path.expanded);
+        pretty_print_path(&self.heat_loss, &path.states);
+        path.cost
+    }
+}
+
+// The map with the path of the crucible
+fn pretty_print_path(heat_loss: &Grid<u8>, path: &[Crucible]) {
+    let mut map = heat_loss.map(|h| char::from(b'0' + h));
+    for c in &path[1..] {
+        map[(c.x, c.y)] = c.d.to_arrow();
+    }
+    debug!("[{},{}] = \n{map}", map.width(), map.height());
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        self.heat_loss = Grid::parse(&lines, "a digit", |c| c.to_digit(10).map(|d| d as u8))?;
+        debug!("[{},{}] = \n{}", self.heat_loss.width(), self.heat_loss.height(),
+               self.heat_loss.render(|h| format!("{h} ")));
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        self.total = self.least_heat_loss();
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,137 @@
+/*
+https://adventofcode.com/2023/day/17
+--- Day 17: Clumsy Crucible ---
+
+ part 2 : ULTRA Crucible !
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_algo::search;
+use aoc_grid::{Direction4, Grid};
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i32,
+    heat_loss: Grid<u8>,
+}
+
+use Direction4::*;
+
+/*
+   Same as part1, with the state being the tile reached with the direction and
+   the number of successive moves in that direction, now 4..10.
+ */
+#[derive(Clone,Copy,Debug,Eq,PartialEq,Hash)]
+struct Crucible {
+    x: usize,
+    y: usize,
+    d: Direction4,
+    len: u8, // 0 for the start only
+}
+
+// Get possible next direction from a starting point and its current
+// moving stats.
+// For Ultra-Crucibles, the initial start or turning
+// requires 4 consecutive straight moves before doing anything else,
+// indicated by the final "bool" in the tuple: caller must iterate
+// on all new_linstead of doing 1 move only.
+fn get_possible_next(d: Direction4, from_len:u8) -> Vec<(Direction4,u8,bool)> {
+    // perpendicular turns
+    let (a,b) = (d.turn_left(), d.turn_right());
+    if from_len == 0 { // starting node only: test everything (ignore d)
+        vec![(Right, 4, true), (Left, 4, true), (Down, 4, true), (Up, 4, true)]
+    } else if from_len < 4 {
+        panic!("get_possible_next called with current len {}. Should be >= 4", from_len);
+        // we don't return just [(d, from_len+1)] because we can't just stop
+        // at this node: technically the next node+1 is not "reachable" as a possible starting
+        // (or stopping) point. Just count accumulate its cost.
+    } else if from_len < 10 {
+        vec![(a,4, true), (b,4, true), (d, from_len+1, false)]
+    } else {
+        vec![(b,4, true), (a,4, true)]
+    }
+}
+
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+             heat_loss: Grid::<u8>::new(1,1,0), // Arbitrary size before replacing it after parsing
+        }
+    }
+
+    // The crucible moves from c, with the heat loss of the tiles entered
+    fn moves(&self, c: &Crucible) -> Vec<(Crucible, i32)> {
+        get_possible_next(c.d, c.len).into_iter()
+            .filter_map(|(d, len, accumulate_move)| {
+                // consume all length without stopping, only the final tile will be the real neighbour
+                let steps = if accumulate_move { len } else { 1 };
+                let (mut x, mut y) = (c.x, c.y);
+                let mut heat = 0;
+                for _ in 0..steps {
+                    // Out of Bound: impossible to move by 4 straight without stopping at
+                    // the border, this is an invalid move.
+                    (x, y) = self.heat_loss.step_towards(x, y, d)?;
+                    heat += self.heat_loss[(x, y)] as i32;
+                }
+                Some((Crucible { x, y, d, len }, heat))
+            })
+            .collect()
+    }
+
+    fn least_heat_loss(&self) -> i32 {
+        let (w, h) = (self.heat_loss.width(), self.heat_loss.height());
+        // ignore heat_loss of the starting tile
+        let start = Crucible { x: 0, y: 0, d: Right, len: 0 };
+        let Some(path) = search::dijkstra([start], |c| self.moves(c), |c| c.x + 1 == w && c.y + 1 == h) else {
+            panic!("No path reaches the destination");
+        };
+        info!("Dijkstra converged in {} iterations", path.expanded);
+        pretty_print_path(&self.heat_loss, &path.states);
+        path.cost
+    }
+}
+
+// The map with the path of the crucible, each move marking the tile it stops at
+fn pretty_print_path(heat_loss: &Grid<u8>, path: &[Crucible]) {
+    let mut map = heat_loss.map(|h| char::from(b'0' + h));
+    for c in &path[1..] {
+        map[(c.x, c.y)] = c.d.to_arrow();
+    }
+    debug!("[{},{}] = \n{map}", map.width(), map.height());
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+        self.heat_loss = Grid::parse(&lines, "a digit", |c| c.to_digit(10).map(|d| d as u8))?;
+        debug!("[{},{}] = \n{}", self.heat_loss.width(), self.heat_loss.height(),
+               self.heat_loss.render(|h| format!("{h} ")));
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        self.total = self.least_heat_loss();
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,7 @@
+/*
+https://adventofcode.com/2023/day/18
+--- Day 18: Lavaduct Lagoon ---
+ */
+
+pub mod part_a;
+pub mod part_b;
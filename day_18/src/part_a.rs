@@ -0,0 +1,148 @@
+/*
+https://adventofcode.com/2023/day/18
+--- Day 18: Lavaduct Lagoon ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::{self, ParseError};
+use aoc_grid::{Direction4, Grid, Location, Point2, Polygon};
+use std::cmp;
+use aoc_common::log::Level;
+use aoc_common::{debug, info, log_enabled};
+use aoc_common::Answer;
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i64,
+}
+
+use Direction4::*;
+
+
+
+
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+
+    // debug print only: the path within its bounding box, and the interior
+    fn draw_lagoon(path: &[(Direction4, usize)], lagoon: &Polygon, (xmin, ymin): (i32, i32), (xmax, ymax): (i32, i32)) {
+        let map_width:usize = (xmax - xmin + 1) as usize;
+        let map_height:usize = (ymax - ymin + 1) as usize;
+        let mut grid_dbg = Grid::<char>::new(map_width, map_height, '.');
+
+        // trace the path starting from a point which will not overflow
+        let start = Point2::new(0 - xmin, 0 - ymin);
+        let mut current = start;
+
+        // '+' where a vertical and a horizontal part of the trench cross
+        let trench = |c: char, vertical: bool| match (c, vertical) {
+            ('.' | '|', true) => '|',
+            ('.' | '-', false) => '-',
+            _ => '+',
+        };
+
+        for &(d,l) in path {
+            for _ in 0..l {
+                let c = &mut grid_dbg[(current.x as usize, current.y as usize)];
+                *c = trench(*c, d.is_vertical());
+
+                current += d.delta();
+
+                let c = &mut grid_dbg[(current.x as usize, current.y as usize)];
+                *c = trench(*c, d.is_vertical());
+            }
+        }
+        let c = &mut grid_dbg[(start.x as usize, start.y as usize)];
+        *c = 'S';
+
+        debug!("[{},{}] = \n{grid_dbg}", grid_dbg.width(), grid_dbg.height());
+
+        for ((x, y), c) in grid_dbg.iter_mut() {
+            let p = Point2::new(x as i64 + xmin as i64, y as i64 + ymin as i64);
+            if *c == '.' && lagoon.locate(p) == Location::Inside {
+                *c = 'o';
+            }
+        }
+        debug!("[{},{}] = \n{grid_dbg}", grid_dbg.width(), grid_dbg.height());
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let mut path = Vec::<(Direction4, usize)>::new();
+        let mut xmin:i32 = 0;
+        let mut ymin:i32 = 0;
+        let mut xmax:i32 = 0;
+        let mut ymax:i32 = 0;
+
+        let mut current = Point2::<i32>::default();  // start path at 0 to compute boundaries
+
+        for (n, input) in aoc_common::lines(reader).enumerate() {
+            let input_clean = &input?;
+            let at_line = |e: ParseError| e.with_line(n + 1);
+            let mut iter = input_clean.split(' ');
+            let ds = parse::next(input_clean, &mut iter, "a direction").map_err(at_line)?;
+
+            let v = parse::next(input_clean, &mut iter, "a length").map_err(at_line)?;
+            let l:usize = parse::number(input_clean, v).map_err(at_line)?;
+
+            let d = match ds {
+                "R" => Right,
+                "L" => Left,
+                "U" => Up,
+                "D" => Down,
+                _  => return Err(at_line(ParseError::at(input_clean, ds, "R, L, U or D")).into()),
+
+            };
+            current += d.delta() * l as i32;
+            path.push((d,l));
+            xmin = cmp::min(xmin, current.x);
+            xmax = cmp::max(xmax, current.x);
+            ymin = cmp::min(ymin, current.y);
+            ymax = cmp::max(ymax, current.y);
+        }
+
+        debug!("Path = {:?}", path);
+        debug!("Bounding box : {xmin},{ymin} -- {xmax},{ymax}");
+
+        // the border trench and the interior dug out, counted on the polygon
+        // joining the centers of the border cubes
+        let lagoon = Polygon::from_moves(Point2::new(0, 0), path.iter().map(|&(d, l)| (d, l as i64)));
+        let total_path = lagoon.boundary_points();
+        let total_area = lagoon.interior_points();
+        info!("Found {total_area} interior blocks in addition to {total_path} border");
+
+        if log_enabled!(Level::Debug) {
+            Self::draw_lagoon(&path, &lagoon, (xmin, ymin), (xmax, ymax));
+        }
+
+        self.total = total_path + total_area;
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+        //self.total = 0;
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
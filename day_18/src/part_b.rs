@@ -0,0 +1,94 @@
+/*
+https://adventofcode.com/2023/day/18
+--- Day 18: Lavaduct Lagoon ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::ParseError;
+use aoc_grid::{Direction4, Point2, Polygon};
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+
+/*
+ * Scanline filling can't work with the new dimensions of part 2.
+ * The trench is a polygon joining the centers of the dug cubes: its
+ * area is given by the Shoelace formula, and the dug cubes are the
+ * lattice points inside or on it, counted with Pick's theorem.
+ */
+
+
+
+
+struct Solver {
+    total: i64,
+}
+
+use Direction4::*;
+
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let mut path = Vec::<(Direction4, i64)>::new();
+
+        for (n, input) in aoc_common::lines(reader).enumerate() {
+            let input_clean = &input?;
+            let at_line = |e: ParseError| e.with_line(n + 1);
+            // extract the final #hexnumber
+            if let Some((_,hexa)) = input_clean.rsplit_once('#') {
+                // Still not understanding string indexing. 
+                // Arbitrary slices are ok, but not direct byte access for a single char, so..
+                let (Some(hexdistance), Some(hexdirection)) = (hexa.get(0..5), hexa.get(5..6)) else {
+                    return Err(at_line(ParseError::at(input_clean, hexa, "6 hexadecimal digits")).into());
+                };
+                let d:Direction4 = match hexdirection {
+                    "0" => Right,
+                    "1" => Down,
+                    "2" => Left,
+                    "3" => Up,
+                    _ => return Err(at_line(ParseError::at(input_clean, hexdirection, "a direction 0 to 3")).into()),
+                };
+                let l:i64 = i64::from_str_radix(hexdistance, 16)
+                    .map_err(|_| at_line(ParseError::at(input_clean, hexdistance, "a hexadecimal distance")))?;
+
+                debug!("Parsed 2 : {:?} for {l}", d);
+
+                path.push((d,l));
+            } else {
+                return Err(at_line(ParseError::at_end(input_clean, "(#<hexadecimal color>)")).into());
+            }
+        }
+
+        debug!("Path = {:?}", path);
+        let lagoon = Polygon::from_moves(Point2::new(0, 0), path);
+        info!("signed surface = {}, {} cubes on the trench",
+              lagoon.signed_double_area() / 2, lagoon.boundary_points());
+
+        self.total = lagoon.enclosed_points();
+        Ok(())
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
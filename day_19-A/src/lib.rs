@@ -0,0 +1,256 @@
+/*
+https://adventofcode.com/2023/day/19
+
+ */
+
+
+use std::io::{self, BufRead};
+use std::collections::HashMap;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+#[derive(Clone)]
+struct Parts {
+    //x: i32,
+    //m: i32,
+    //a: i32,
+    //s: i32,
+    ratings: HashMap::<char, i32>  // easier indexing
+}
+
+impl Parts {
+    // parse from the complete {...} string, curly braces included
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+	// trim first and last char {}
+	let Some(l) = line.strip_prefix('{').and_then(|l| l.strip_suffix('}')) else {
+	    return Err(ParseError::new(1, "{<ratings>}", line));
+	};
+	let mut ratings = HashMap::<char, i32>::new();
+	for s in l.split(',') {
+	    let Some((c,v)) = s.split_once('=') else {
+		return Err(ParseError::at(line, s, "<rating>=<value>"));
+	    };
+	    // get the first character (should be the only one)
+	    let Some(rating) = c.chars().next() else {
+		return Err(ParseError::at(line, s, "a rating name"));
+	    };
+	    let value = parse::number(line, v)?;
+	    ratings.insert(rating, value);
+	}
+
+	Ok(Self{
+	    ratings,
+	})
+    }
+
+    // return the numeric value for a rating name
+    fn get(&self, c:char) -> Option<i32> {
+	self.ratings.get(&c).copied()
+    }
+
+    // sum all ratings
+    fn ratings_sum(&self) -> i32 {
+	self.ratings.iter().fold(0, |acc, v| acc+*v.1)
+    }
+}
+
+#[derive(Clone)]
+struct Rule {
+    rating: char,   // x/m/a/s
+    cmp_gt: bool,   // true if need to ">" else "<"
+    cmp_value: i32,
+    dest: String,  // next workflow name
+}
+
+impl Rule {
+    // sss[</>]num:sss2
+    fn from_str(l: &str) -> Result<Self, ParseError> {
+	let Some((cond, dest)) = l.split_once(':') else {
+	    return Err(ParseError::new(1, "<rating><op><value>:<workflow>", l));
+	};
+	let rating:&str;
+	let value_s:&str;
+	let cmp_op:bool;
+	if let Some((rating1,value1)) = cond.split_once('<') {
+	    rating = rating1;
+	    value_s = value1;
+	    cmp_op = false;
+	} else if let Some((rating2,value2)) = cond.split_once('>') {
+	    rating = rating2;
+	    value_s = value2;
+	    cmp_op = true;
+	} else {
+	    return Err(ParseError::new(1, "a rule with a < or > operator", cond));
+	}
+	let value = parse::number(l, value_s)?;
+	// get the first character (should be the only one)
+	let Some(rating) = rating.chars().next() else {
+	    return Err(ParseError::new(1, "a rating name", cond));
+	};
+	Ok(Self {
+	    rating,
+	    cmp_gt: cmp_op,
+	    cmp_value: value,
+	    dest: String::from(dest),
+	})
+	
+    }
+
+    fn matches(&self, p: &Parts) -> Option<&str> {
+	let v = p.get(self.rating)?;
+	let cmp_success:bool = if self.cmp_gt { v > self.cmp_value }
+	else { v < self.cmp_value };
+	if cmp_success {
+	    Some(self.dest.as_str())
+	} else {
+	    None
+	}
+	
+    }
+}
+	
+
+struct Workflow {
+    rules: Vec::<Rule>,
+    default: String, // final condition name if not matching rules
+}
+
+impl Workflow {
+    // expects the string between {...}  (curly braces not included)
+    fn from_str(l: &str) -> Result<Self, ParseError> {
+	let mut list:Vec<&str> = l.split(',').collect();
+	let default = list.pop().unwrap();
+	let mut rules = Vec::<Rule>::new();
+	for s in list {
+	    rules.push(Rule::from_str(s).map_err(|e| e.within(l, s))?);
+	}
+	Ok(Self {
+	    rules,
+	    default: String::from(default),
+	})
+    }
+    
+    // apply the rules to a Parts and return the next
+    // workflow to match
+    fn apply(&self, p: &Parts) -> &str {
+	for r in &self.rules {
+	    if let Some(wnext) = r.matches(p) {
+		return wnext;
+	    }
+	}
+	self.default.as_str()
+    }
+}
+
+
+// Checks, once all the workflows are parsed, that each rule sends the
+// parts to a known workflow, and that the starting "in" one exists
+fn check_workflows(wf_lines: &[(usize, String)], workflows: &HashMap<String, Workflow>) -> Result<(), ParseError> {
+    for (n, l) in wf_lines {
+	let rules = l.trim_end_matches('}').split_once('{').map_or("", |(_, r)| r);
+	for r in rules.split(',') {
+	    let dest = r.split_once(':').map_or(r, |(_, d)| d);
+	    if dest != "A" && dest != "R" && !workflows.contains_key(dest) {
+		return Err(ParseError::at(l, dest, "a workflow name").with_line(*n));
+	    }
+	}
+    }
+    if !workflows.contains_key("in") {
+	// reported on the empty line ending the workflows
+	return Err(ParseError::new(1, "an \"in\" workflow", "").with_line(wf_lines.len() + 1));
+    }
+    Ok(())
+}
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i32,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+        // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+	// parse the workflows
+        let mut workflows = HashMap::<String, Workflow>::new();
+	let mut wf_lines = Vec::new();
+        let mut lines = aoc_common::lines(reader).enumerate();
+        for (n, input) in lines.by_ref() {
+	    let input_clean = &input?;
+	    if input_clean.is_empty() {
+		break; // end of section
+	    }
+	    let Some(wf_str) = input_clean.strip_suffix('}') else { // drop the final '}'
+		return Err(ParseError::at_end(input_clean, "}").with_line(n + 1).into());
+	    };
+	    let Some((name,wf_str)) = wf_str.split_once('{') else {
+		return Err(ParseError::new(1, "<name>{<rules>}", input_clean.as_str()).with_line(n + 1).into());
+	    };
+	    let workflow = Workflow::from_str(wf_str)
+		.map_err(|e| e.within(input_clean, wf_str).with_line(n + 1))?;
+
+	    workflows.insert(String::from(name), workflow);
+	    wf_lines.push((n + 1, input_clean.clone()));
+        }
+	check_workflows(&wf_lines, &workflows)?;
+
+
+	info!("Parsed {} workflows", workflows.len());
+
+	// parse the parts
+        let mut parts = Vec::<Parts>::new();
+        for (n, input) in lines {
+	    let input_clean = &input?;
+	    let part = Parts::from_str(input_clean).map_err(|e| e.with_line(n + 1))?;
+	    parts.push(part);
+        }
+	info!("Parsed {} parts", parts.len());
+
+
+	// solve parts
+	for p in &parts {
+	    // starting workflow
+	    let mut next_wf: &str = "in";
+	    while next_wf != "A" && next_wf != "R" {
+		let wf = &workflows[next_wf];
+		next_wf = wf.apply(p);
+	    }
+	    if next_wf == "A" {
+		let partsum = p.ratings_sum();
+		debug!("Part is accepted with +r = {partsum}");
+		self.total += partsum;
+	    } else {
+		debug!("Part is rejected");
+	    }
+	}
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
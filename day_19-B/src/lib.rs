@@ -0,0 +1,309 @@
+/*
+https://adventofcode.com/2023/day/19
+
+ */
+
+
+use std::io::{self, BufRead};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use aoc_algo::IntervalSet;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+const RMAX:i32 = 4001;  // for range ..RMAX (..=4000 would be of type RangeInclusive)
+
+// represent the ranges of each ratings.
+// (for example: x[1..4000] & m[1..50] & a[123..843] & s[2400..4000])
+#[derive(Clone,Debug)]
+struct PartsRange {
+    ratings: HashMap::<char, IntervalSet<i32>>
+}
+
+impl PartsRange {
+
+    // create the default Full range (1..=4000)
+    fn new() -> Self {
+	let mut ratings = HashMap::<char, IntervalSet<i32>>::new();
+	for c in ['x','m','a','s'] {
+	    ratings.insert(c, IntervalSet::from(1..RMAX));
+	}
+
+	Self{
+	    ratings,
+	}
+    }
+
+    // return the range for a rating name, or 1..4000 when not found
+    fn get(&self, c:char) -> IntervalSet<i32> {
+	match self.ratings.get(&c) {
+	    None => IntervalSet::from(1..RMAX),
+	    Some(v) => v.clone(),
+	}
+    }
+
+    fn set(&mut self, c:char, r:IntervalSet<i32>) {
+	self.ratings.insert(c,r);
+    }
+
+    // return true if any rating has an empty range
+    fn is_empty(&self) -> bool {
+	self.ratings.iter().fold( false, |acc, (_,r)| acc || r.is_empty() )
+    }
+
+    // return the possible combinations of internal ranges
+    fn combinations(&self) -> i64 {
+	self.ratings.iter().fold( 1i64, |acc, (_,r)| acc * r.count() )
+
+    }
+    
+    // return the intersection of the ranges for the two elements.
+    fn intersect(&self, other:&PartsRange) -> PartsRange {
+	let mut ratings = HashMap::<char, IntervalSet<i32>>::new();
+	for (c,r) in &self.ratings {
+	    ratings.insert(*c, r.intersection(&other.get(*c)));
+	}
+
+	Self{
+	    ratings,
+	}
+    }
+}
+
+#[derive(Clone)]
+struct Rule {
+    rating: char,   // x/m/a/s
+    cmp_gt: bool,   // true if need to ">" else "<"
+    cmp_value: i32,
+    dest: String,  // next workflow name
+}
+
+impl Rule {
+    // sss[</>]num:sss2
+    fn from_str(l: &str) -> Result<Self, ParseError> {
+	let Some((cond, dest)) = l.split_once(':') else {
+	    return Err(ParseError::new(1, "<rating><op><value>:<workflow>", l));
+	};
+	let rating:&str;
+	let value_s:&str;
+	let cmp_op:bool;
+	if let Some((rating1,value1)) = cond.split_once('<') {
+	    rating = rating1;
+	    value_s = value1;
+	    cmp_op = false;
+	} else if let Some((rating2,value2)) = cond.split_once('>') {
+	    rating = rating2;
+	    value_s = value2;
+	    cmp_op = true;
+	} else {
+	    return Err(ParseError::new(1, "a rule with a < or > operator", cond));
+	}
+	let value = parse::number(l, value_s)?;
+	// get the first character (should be the only one)
+	let Some(rating) = rating.chars().next() else {
+	    return Err(ParseError::new(1, "a rating name", cond));
+	};
+	Ok(Self {
+	    rating,
+	    cmp_gt: cmp_op,
+	    cmp_value: value,
+	    dest: String::from(dest),
+	})
+	
+    }
+
+//    fn matches(&self, p: &Parts) -> Option<&str> {
+//	let v = p.get(self.rating)?;
+//	let cmp_success:bool = if self.cmp_gt { v > self.cmp_value }
+//	else { v < self.cmp_value };
+//	if cmp_success {
+//	    Some(self.dest.as_str())
+//	} else {
+//	    None
+//	}
+//    }
+}
+
+impl PartsRange {
+    // 
+    fn from_rule(rule: &Rule) -> Self {
+	let mut p = Self::new();
+	let limit = rule.cmp_value;
+	if rule.cmp_gt {
+	    p.set(rule.rating, IntervalSet::from(limit+1..RMAX));
+	} else {
+	    p.set(rule.rating, IntervalSet::from(1..limit));
+	}
+
+	p
+    }
+    // inverted/non matching rule: all the other ratings values
+    fn from_invert_rule(rule: &Rule) -> Self {
+	let mut p = Self::new();
+	let matching = Self::from_rule(rule).get(rule.rating);
+	p.set(rule.rating, IntervalSet::from(1..RMAX).difference(&matching));
+
+	p
+    }
+}
+	
+
+struct Workflow {
+    rules: Vec::<Rule>,
+    default: String, // final condition name if not matching rules
+}
+
+impl Workflow {
+    // expects the string between {...}  (curly braces not included)
+    fn from_str(l: &str) -> Result<Self, ParseError> {
+	let mut list:Vec<&str> = l.split(',').collect();
+	let default = list.pop().unwrap();
+	let mut rules = Vec::<Rule>::new();
+	for s in list {
+	    rules.push(Rule::from_str(s).map_err(|e| e.within(l, s))?);
+	}
+	Ok(Self {
+	    rules,
+	    default: String::from(default),
+	})
+    }
+    
+    // apply the rules to an input range and return the list
+    // of matching sub-ranges + next-workflow
+
+    fn apply_to_range(&self, pr: &PartsRange) -> Vec<(String,PartsRange)> {
+	let mut vec = Vec::<(String, PartsRange)>::new();
+	let mut pr = pr.clone();
+	for r in &self.rules {
+	    let r_match = PartsRange::from_rule(r);
+	    let r_non_match = PartsRange::from_invert_rule(r);
+	    let matching = pr.intersect(&r_match);
+	    pr = pr.intersect(&r_non_match);
+	    if !matching.is_empty() {
+		vec.push( (r.dest.clone(), matching) );
+	    } else {
+		debug!("Workflow apply_to_range() intermediate range can never match do next worflow");
+	    }
+	}
+	if !pr.is_empty() {
+	    vec.push( (self.default.clone(), pr) );
+	} else {
+	    debug!("Workflow apply_to_range() final range can never match do default worflow");
+	}
+	
+	vec
+    }
+}
+
+
+// Checks, once all the workflows are parsed, that each rule sends the
+// parts to a known workflow, and that the starting "in" one exists
+fn check_workflows(wf_lines: &[(usize, String)], workflows: &HashMap<String, Workflow>) -> Result<(), ParseError> {
+    for (n, l) in wf_lines {
+	let rules = l.trim_end_matches('}').split_once('{').map_or("", |(_, r)| r);
+	for r in rules.split(',') {
+	    let dest = r.split_once(':').map_or(r, |(_, d)| d);
+	    if dest != "A" && dest != "R" && !workflows.contains_key(dest) {
+		return Err(ParseError::at(l, dest, "a workflow name").with_line(*n));
+	    }
+	}
+    }
+    if !workflows.contains_key("in") {
+	// reported on the empty line ending the workflows
+	return Err(ParseError::new(1, "an \"in\" workflow", "").with_line(wf_lines.len() + 1));
+    }
+    Ok(())
+}
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i64,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+        // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+	// parse the workflows
+        let mut workflows = HashMap::<String, Workflow>::new();
+	let mut wf_lines = Vec::new();
+        for (n, input) in aoc_common::lines(reader).enumerate() {
+	    let input_clean = &input?;
+	    if input_clean.is_empty() {
+		break; // end of section
+	    }
+	    let Some(wf_str) = input_clean.strip_suffix('}') else { // drop the final '}'
+		return Err(ParseError::at_end(input_clean, "}").with_line(n + 1).into());
+	    };
+	    let Some((name,wf_str)) = wf_str.split_once('{') else {
+		return Err(ParseError::new(1, "<name>{<rules>}", input_clean.as_str()).with_line(n + 1).into());
+	    };
+	    let workflow = Workflow::from_str(wf_str)
+		.map_err(|e| e.within(input_clean, wf_str).with_line(n + 1))?;
+
+	    workflows.insert(String::from(name), workflow);
+	    wf_lines.push((n + 1, input_clean.clone()));
+        }
+	check_workflows(&wf_lines, &workflows)?;
+
+
+	info!("Parsed {} workflows", workflows.len());
+
+
+	// solve splitting range of parts through all worflows
+	let mut queue = VecDeque::<(String, PartsRange)>::new();
+	queue.push_back( (String::from("in"), PartsRange::new()) );
+	// all the sub-ranges reaching the final Accepted state
+	let mut ranges_to_a = Vec::<PartsRange>::new();
+
+	while let Some((wf, sr)) = queue.pop_front() {
+	    let wf = &workflows[&wf];
+	    for (next_wf, next_range) in wf.apply_to_range(&sr) {
+		if next_wf == "A" {
+		    ranges_to_a.push(next_range);
+		} else if next_wf != "R" {
+		    queue.push_front( (next_wf, next_range) );
+		}
+	    }
+	}
+	info!("Final Accepted subranges are counted {}",
+		  ranges_to_a.len());
+
+	//eprintln!("ranges = {:?}", ranges_to_a);
+
+	// If the subranges are never overlapping on all of their
+	// subparts at the same time (which should always be the case
+	// by their method of construction):
+	// Sum of each range internal product combinations
+	self.total = ranges_to_a.iter().map(|r| r.combinations() ).sum();
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
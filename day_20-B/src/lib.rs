@@ -0,0 +1,347 @@
+/*
+https://adventofcode.com/2023/day/20
+--- Day 20: Pulse Propagation ---
+ */
+
+
+/*
+Iterating until RX receives the correct input doesn't work.
+RX always receives 0 LOW and 4..10 HIGH for MANY iterations
+It receive input from several & nodes, which themselves receives from &
+so there is a lot of stages that need to align.
+
+With all those & and ~ it looks a bit like a SAT problem, with added 
+feedback-loop to make it more fun. 
+But I don't think it can really be answered with a SAT solver.
+
+Instead, do a bit like Day 14 part 2:
+find the cycles for each of the individual & inputs,
+and compute the lowest common denominator
+
+*/
+
+/*
+This solution is based on the particular individual input data
+I got, clearly not generalizable to any arbitrary turing-complete state-machine.
+
+In particular the final modules path I have are of the form (in reverse order)
+
+RX  <-- &bq <--- { &vg            <-- &lx  <-- { many lx sources}
+                      {many more} <-/  
+                 { &kp            <-- &db
+                 { &gc            <-- &qz
+                 { &tx            <-- &sd
+                
+So RX receives LOW only when bq receives HIGH from the 4 others;
+the 4 others (vg,kp,gc,tx) have a single input so they send HIGH only when their
+input is LOW (from lx, db, qz, sd)
+
+The 4 others each send HIGH during a single press every N presses, from
+the press N, N being different for each (the size of the counter built
+with its flip-flops). They all do during the same press, and RX receives
+LOW, first at the LCM of their Ns.
+
+Algo finds the conjunction sending to RX and its inputs, then runs
+run_button() in loop until each input has sent HIGH twice, checking
+the second time is at twice the first one.
+
+Monitoring the state left at the end of a press, or the lx/db/qz/sd
+modules one level deeper, finds nothing: the inputs of bq send HIGH
+then reset to LOW within the same press, and lx/db/qz/sd never send LOW
+during the first 100K presses.
+ */
+
+use std::io::{self, BufRead};
+use aoc_algo::number;
+use aoc_common::parse::{self, ParseError};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use aoc_common::{debug, info};
+use aoc_common::Answer;
+
+// We'll map short pulse to false, long pulse to true
+// (Could have been an enum, initial idea was not to have any custom symbol)
+type Pulse = bool;
+const LOW:Pulse = false;
+const HIGH:Pulse = true;
+
+// XXX some ad-hoc inheritance/polymorphism in rust ?
+#[derive(Clone,Debug)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop(bool), // internal state on or off
+    Conjunction(HashMap<String,Pulse>), // recent inputs
+}
+use ModuleType::*;
+
+#[derive(Clone,Debug)]
+struct Module {
+    mtype: ModuleType,
+    destinations: Vec<String>,
+    sent_high_once: bool, // sent a HIGH pulse once this cycle (may not be the last sent)
+}
+
+impl Module {
+    fn new_broadcaster(dest: Vec<String> ) -> Self {
+	Self {
+	    mtype: Broadcaster,
+	    destinations: dest,
+	    sent_high_once: false,
+	}
+    }
+
+    fn new_flipflop(dest: Vec<String> ) -> Self {
+	Self {
+	    mtype: FlipFlop(false),
+	    destinations: dest,
+	    sent_high_once: false,
+	}
+    }
+
+    fn new_conjunction(dest: Vec<String> ) -> Self {
+	Self {
+	    mtype: Conjunction(HashMap::<String,Pulse>::new()),
+	    destinations: dest,
+	    sent_high_once: false,
+	}
+    }
+
+    fn insert_input(&mut self, input_name:&str) {
+	if let Conjunction(ref mut inputs) = self.mtype { inputs.insert(input_name.to_string(), LOW); }
+    }
+
+    // update last_sent
+    // returns the ordered list of the output pulses to destinations.
+    fn send_destinations(&mut self, pulse: Pulse) -> Vec<(String, Pulse)> {
+	if pulse == HIGH {
+	    self.sent_high_once = true;
+	}
+	self.destinations.iter().map(|n| (n.clone(), pulse)).collect()
+    }
+    // Process an input pulse.
+    // Update its internal state and return the new pulses to send.
+    fn receive_pulse_from(&mut self, pulse: Pulse, origin: &str) -> Vec<(String, Pulse)> {
+	match self.mtype {
+	    // re-send the same pulse to all dests
+	    Broadcaster => self.send_destinations(pulse),
+	    FlipFlop(ref mut internal_state) => if pulse == HIGH {
+		Vec::new() // nothing happens
+	    } else {
+		let send_pulse = if *internal_state { LOW } else { HIGH };
+		*internal_state = !*internal_state;
+		self.send_destinations(send_pulse)
+	    },
+	    Conjunction(ref mut inputs) => {
+		inputs.insert(origin.to_string(), pulse);
+		let all_high = inputs.iter().fold(true, |acc, (_,&v)| acc && v==HIGH);
+		let send_pulse = if all_high { LOW } else { HIGH };
+		self.send_destinations(send_pulse)
+	    }
+	}
+    }
+
+    // Reset the "sent_high_once" for this new cycle
+    fn reset_stats(&mut self) {
+	self.sent_high_once = false;
+    }
+}
+
+
+
+struct Network {
+    modules: HashMap<String, Module>,
+}
+
+
+impl Network {
+    // Send the initial low pulse from Button to Broadcaster.
+    // Follow the network activity until no more pulse is emitted.
+    fn run_button(&mut self) -> i32 {
+
+	let mut queue = VecDeque::<(String, String, Pulse)>::new(); // source, dest, pulse value
+
+	queue.push_back(("button".to_string(), "broadcaster".to_string(), LOW));
+
+	let mut converge=0;
+	while let Some((origin, dest, p)) = queue.pop_front() {
+	    converge += 1;
+
+	    if let Some(module) = self.modules.get_mut(&dest) {
+		let next_dests = module.receive_pulse_from(p, &origin);
+		for (next_name, next_pulse) in next_dests {
+		    queue.push_back((dest.clone(), next_name, next_pulse));
+		}
+	    } else {
+		//eprintln!("destination Module name {dest} not found in network map !");
+		// we know, this is RX...
+	    }
+	}
+
+	converge
+    }
+
+    // perform an initial pass to find all "origins" to each modules (from the
+    // existing list of "destinations").
+    // Required so Conjunction modules start with ALL their sources states correctly set.
+    fn initialize_origins(&mut self) {
+
+	// Rust borrow checker problems:
+	// we need to do a double-loop on the modules hashmap,
+	// to modify the element pointed in the inner loop.
+	// this leads to intractable incompatibilities of mut/non mut.
+	// Instead, copy the hashmap to do all the modifications
+	// then copy back (in-place not possible)
+	// Another solution could have been to copy only the set
+	// of module names string, and do double lookups in the (mutable) hashmap ?
+
+	let mut final_modules = self.modules.clone();
+
+	for (k,m) in self.modules.iter() {
+	    let origin = k;
+	    for dest in m.destinations.iter() {
+		if let Some(dest_m) = final_modules.get_mut(dest) {
+		    dest_m.insert_input(origin);
+		} else {
+		    // an output only module, like rx
+		    debug!("{dest} has no destination (from {origin})");
+		}
+	    }
+	}
+	self.modules = final_modules;
+    }
+
+    // The conjunction sending to rx, with its inputs by name.
+    // None when rx is not fed by a single conjunction.
+    fn rx_feeder(&self) -> Option<(&str, Vec<&str>)> {
+	let mut feeders = self.modules.iter()
+	    .filter(|(_, m)| m.destinations.iter().any(|d| d == "rx"));
+	let (name, module) = feeders.next()?;
+	if feeders.next().is_some() {
+	    return None;
+	}
+	match &module.mtype {
+	    Conjunction(inputs) => {
+		let mut inputs: Vec<&str> = inputs.keys().map(|s| s.as_str()).collect();
+		inputs.sort();
+		Some((name, inputs))
+	    }
+	    _ => None,
+	}
+    }
+
+    // Number of button presses until rx receives a LOW pulse: the LCM of
+    // the cycles of the inputs of its feeder, each sending HIGH once every
+    // cycle from the end of the first one.
+    fn presses_for_rx(&mut self, max_presses: u64) -> Result<i64, String> {
+	let (feeder, inputs) = self.rx_feeder()
+	    .ok_or("rx is not fed by a single conjunction")?;
+	info!("rx is fed by &{feeder}, from {inputs:?}");
+	let inputs: Vec<String> = inputs.into_iter().map(|s| s.to_string()).collect();
+
+	// the first two presses during which each input sent HIGH
+	let mut high_at = vec![Vec::<u64>::new(); inputs.len()];
+	for k in 1..=max_presses {
+	    for m in self.modules.values_mut() {
+		m.reset_stats();
+	    }
+	    let converge = self.run_button();
+	    if (k % 1000) == 0 {
+		debug!("#{k} run converged in {converge} iterations");
+	    }
+	    for (name, presses) in inputs.iter().zip(high_at.iter_mut()) {
+		if presses.len() < 2 && self.modules[name].sent_high_once {
+		    presses.push(k);
+		}
+	    }
+	    if high_at.iter().all(|p| p.len() == 2) {
+		break;
+	    }
+	}
+
+	let mut sizes = Vec::<i64>::new();
+	for (name, presses) in inputs.iter().zip(&high_at) {
+	    match presses[..] {
+		[first, second] if second == 2 * first => {
+		    info!("{name} sends HIGH every {first} presses");
+		    sizes.push(first as i64);
+		}
+		[first, second] => return Err(format!(
+		    "{name} sends HIGH at presses {first} and {second}, not a cycle from the start")),
+		_ => return Err(format!(
+		    "{name} does not send HIGH twice within {max_presses} presses")),
+	    }
+	}
+	number::lcm_all(sizes).ok_or_else(|| "the common cycle of the rx inputs overflows".to_string())
+    }
+
+}
+
+// Presses watched for the cycles of the rx inputs, well over their
+// length of about 4000
+const MAX_PRESSES: u64 = 100_000;
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i64,
+    network: Network,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+	     network: Network {modules: HashMap::new(),},
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process one text line of input
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+	if l.trim().is_empty() {
+	    return Ok(());
+	}
+	let (mname, dests) = parse::key_values(l, "->")?;
+	let dests:Vec<String> = dests.into_iter().map(|s| s.to_string()).collect();
+	let (name, module) = if let Some(name) = mname.strip_prefix('%') {
+	    (name, Module::new_flipflop(dests))
+	} else if let Some(name) = mname.strip_prefix('&') {
+	    (name, Module::new_conjunction(dests))
+	} else {
+	    (mname, Module::new_broadcaster(dests))
+	};
+	self.network.modules.insert(name.to_string(), module);
+	Ok(())
+    }
+
+    // The whole network is needed before pressing the button
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+	for (n, l) in aoc_common::lines(reader).enumerate() {
+	    self.process(&l?).map_err(|e| e.with_line(n + 1))?;
+	}
+	info!("Network has {} modules",
+		  self.network.modules.len());
+	self.network.initialize_origins();
+
+	// Bruteforcing doesn't work, who would have guessed: the cycles
+	// are about 4000 presses long
+	self.total = self.network.presses_for_rx(MAX_PRESSES)
+	    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+	info!("rx receives LOW after {} presses", self.total);
+	Ok(())
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,98 @@
+/*
+https://adventofcode.com/2023/day/21
+--- Day 21: Step Counter ---
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_grid::Grid;
+use std::collections::HashSet;
+use aoc_common::debug;
+use aoc_common::Answer;
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total: usize,
+    rock_map: Grid<bool>,
+    start: (usize, usize),
+    steps: usize,
+}
+
+
+
+impl Solver {
+    fn new(steps: usize) -> Self {
+        Self{total : 0,
+	     rock_map: Grid::new(0,0, false),
+	     start : (0,0),
+	     steps,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+	let lines = aoc_common::lines(reader).collect::<io::Result<Vec<_>>>()?;
+	let map = Grid::parse(&lines, "'.', '#' or 'S'", |c| "S.#".contains(c).then_some(c))?;
+	if let Some(start) = map.find(|&c| c == 'S') {
+	    self.start = start;
+	}
+
+        self.rock_map = map.map(|&c| c == '#');
+	debug!("[{},{}] = \n{}", self.rock_map.width(), self.rock_map.height(),
+	       self.rock_map.render(|&r| if r { '*' } else { '.' }));
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+	// last position reached after N steps.
+	// hashset so that similar tiles reached by
+	// different paths count only once.
+	// could have been also implemented by marking
+	// true on a Grid, but easier to set and iterate
+	// (and doesn't depend on any dimension...)
+	let mut tiles = HashSet::<(usize,usize)>::new();
+
+	tiles.insert(self.start);
+	for _steps in 0..self.steps {
+	    let mut next_tiles = HashSet::<(usize,usize)>::new();
+	    for &(x,y) in tiles.iter() {
+		// outside of the map is all rocks
+		for n in self.rock_map.neighbours4(x, y) {
+		    if !self.rock_map[n] {
+			next_tiles.insert(n);
+		    }
+		}
+	    }
+	    tiles = next_tiles;
+	}
+	
+        self.total = tiles.len();
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+pub const PARAMS: &[aoc_common::Param] = &[aoc_common::Param {
+    name: "steps",
+    help: "number of steps of the elf (default 64, 6 for the sample)",
+    min: 0,
+    max: i64::MAX,
+}];
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+    let steps = params.get("steps").unwrap_or(64);
+    aoc_common::run(Solver::new(steps as usize), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
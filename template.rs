@@ -4,10 +4,10 @@ https://adventofcode.com/2023/day/<DAY>
  */
 
 
-use std::io;
-use std::str::FromStr;
-use regex::Regex;
-use std::ops::Range;
+use std::io::{self, BufRead};
+use aoc_common::parse::ParseError;
+use aoc_common::debug;
+use aoc_common::Answer;
 
 
 // Solver for this particular problem
@@ -21,44 +21,30 @@ impl Solver {
         Self{total : 0,
         }
     }
+}
 
+impl aoc_common::Solver for Solver {
     // process one text line of input
-    fn process(&mut self, l: &str) {
+    fn process(&mut self, l: &str) -> Result<(), ParseError> {
+        debug!("{l}");
+        Ok(())
     }
 
 
     fn postprocess(&mut self) {
     }
     
-    // Returns the final string of expected output
-    fn result(&mut self) -> String {
-        self.postprocess();
-        self.total.to_string()
+    // Returns the answer of the puzzle
+    fn result(&self) -> Answer {
+        self.total.into()
     }
 }
 
 /* common to all problems */
-fn main() {
-
-    let mut s = Solver::new();
-
-    let mut input = String::new();
-    loop {
-        match io::stdin().read_line(&mut input) {
-            Err(_) => { println!("input error, exit"); break; }
-            Ok(0) => {
-                eprintln!("Eof detected");
-                break;
-            },
-            Ok(_) => {
-                let input_clean = input.trim(); // remove the \n
-                s.process(input_clean);
-            }
-        }
-        // must clear for next loop
-        input = String::from("");
-    }
-
-    println!("{}", s.result());
+pub fn run(reader: &mut dyn BufRead) -> io::Result<Answer> {
+    aoc_common::run(Solver::new(), reader)
+}
 
+pub fn solve(input: &str) -> io::Result<Answer> {
+    run(&mut input.as_bytes())
 }